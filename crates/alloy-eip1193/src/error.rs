@@ -24,14 +24,14 @@ use thiserror::Error;
 use wasm_bindgen::JsValue;
 use wasm_bindgen::JsCast;
 use alloy::transports::{TransportError, TransportErrorKind};
-use alloy_json_rpc::ErrorPayload;
+use alloy_json_rpc::{ErrorPayload, RpcError};
 
 /// EIP-1193 Provider Error
 ///
 /// Error type for EIP-1193 provider operations covering wallet-specific error codes.
 /// This focuses on EIP-1193 provider errors (4000-4999) which are unique to browser wallets.
 /// Standard JSON-RPC errors are handled by Alloy's ErrorPayload system.
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum Eip1193Error {
     // ============================================================================
     // EIP-1193 Provider Errors (4000-4999)
@@ -225,6 +225,14 @@ impl Eip1193Error {
         matches!(self, Self::Unauthorized(_) | Self::UserRejectedRequest)
     }
 
+    /// Check if the wallet doesn't implement the requested method
+    ///
+    /// Covers EIP-1193's 4200 and JSON-RPC's -32601 (method not found), which some
+    /// wallets return instead.
+    pub fn is_unsupported_method(&self) -> bool {
+        matches!(self, Self::UnsupportedMethod(_) | Self::UnknownError { code: -32601, .. })
+    }
+
     /// Check if this error is related to chain connectivity
     pub fn is_chain_error(&self) -> bool {
         matches!(
@@ -242,10 +250,12 @@ impl Eip1193Error {
         TransportErrorKind::custom(self)
     }
 
-    /// Try to extract an Eip1193Error from a TransportError by parsing the error message
+    /// Try to extract an Eip1193Error from a TransportError
     ///
-    /// Since Alloy's TransportError doesn't preserve the original error type after conversion,
-    /// we parse the error message to detect EIP-1193 error codes and reconstruct the error.
+    /// Errors produced by `Eip1193Transport` are boxed as custom transport errors, so they
+    /// are recovered by downcasting. JSON-RPC error responses carrying an EIP-1193 code
+    /// (4000-4999) are mapped via that code.
+    /// For anything else we fall back to parsing the error message for EIP-1193 error codes.
     ///
     /// # Example
     /// ```rust,ignore
@@ -261,6 +271,18 @@ impl Eip1193Error {
     /// }
     /// ```
     pub fn from_transport_error(err: &TransportError) -> Option<Self> {
+        match err {
+            RpcError::Transport(TransportErrorKind::Custom(inner)) => {
+                if let Some(eip1193_err) = inner.downcast_ref::<Self>() {
+                    return Some(eip1193_err.clone());
+                }
+            }
+            RpcError::ErrorResp(payload) if (4000..5000).contains(&payload.code) => {
+                return Some(Self::from_error_payload(payload));
+            }
+            _ => {}
+        }
+
         let err_str = err.to_string();
 
         // Try to extract EIP-1193 error codes from the message
@@ -386,6 +408,19 @@ mod tests {
         let unauthorized = Eip1193Error::Unauthorized("test".into());
         assert!(unauthorized.is_authorization_error());
         assert!(!unauthorized.is_chain_error());
+
+        assert!(Eip1193Error::UnsupportedMethod("test".into()).is_unsupported_method());
+        assert!(Eip1193Error::from_code(-32601, "Method not found".into()).is_unsupported_method());
+        assert!(!Eip1193Error::from_code(-32602, "Invalid params".into()).is_unsupported_method());
+    }
+
+    #[test]
+    fn test_from_transport_error_roundtrip() {
+        let err = Eip1193Error::UnrecognizedChain(42161).into_transport_error();
+        match Eip1193Error::from_transport_error(&err) {
+            Some(Eip1193Error::UnrecognizedChain(chain_id)) => assert_eq!(chain_id, 42161),
+            other => panic!("Expected UnrecognizedChain, got {:?}", other),
+        }
     }
 
     #[test]
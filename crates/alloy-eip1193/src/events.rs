@@ -0,0 +1,172 @@
+//! Typed EIP-1193 provider events
+//!
+//! EIP-1193 providers emit `accountsChanged`, `chainChanged`, `connect`, `disconnect` and
+//! `message` events with untyped JavaScript payloads. This module parses them into
+//! [`ProviderEvent`] and exposes them as a [`futures_util::Stream`] through
+//! [`Eip1193Transport::events`](crate::Eip1193Transport::events).
+
+use alloy::primitives::Address;
+use futures_channel::mpsc::{self, UnboundedReceiver};
+use futures_util::{Stream, StreamExt};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::js_sys;
+use crate::error::Eip1193Error;
+use crate::subscription::Subscription;
+use crate::transport::Eip1193Transport;
+
+/// An event emitted by an EIP-1193 provider
+#[derive(Debug, Clone)]
+pub enum ProviderEvent {
+    /// The accounts exposed to the dApp changed; empty when the wallet was locked or disconnected
+    AccountsChanged(Vec<Address>),
+    /// The wallet switched to another chain
+    ChainChanged(u64),
+    /// The provider can submit RPC requests to the given chain
+    Connect {
+        /// Chain the provider connected to
+        chain_id: u64,
+    },
+    /// The provider can no longer submit RPC requests to any chain
+    Disconnect(Eip1193Error),
+    /// A message the consumer should be notified of, e.g. an `eth_subscription` update
+    Message {
+        /// Message type, e.g. `"eth_subscription"`
+        r#type: String,
+        /// Message payload
+        data: serde_json::Value,
+    },
+}
+
+impl ProviderEvent {
+    /// Names of all events defined by EIP-1193
+    pub const NAMES: [&'static str; 5] = ["accountsChanged", "chainChanged", "connect", "disconnect", "message"];
+
+    /// Parse the payload of the EIP-1193 event named `event`
+    pub fn parse(event: &str, payload: &JsValue) -> Result<Self, Eip1193Error> {
+        match event {
+            "accountsChanged" => {
+                let accounts = payload
+                    .dyn_ref::<js_sys::Array>()
+                    .ok_or_else(|| Eip1193Error::SerializationError("Accounts payload is not an array".into()))?;
+
+                accounts
+                    .iter()
+                    .map(|account| {
+                        account
+                            .as_string()
+                            .ok_or_else(|| Eip1193Error::SerializationError("Account is not a string".into()))?
+                            .parse::<Address>()
+                            .map_err(|e| Eip1193Error::SerializationError(format!("Invalid account: {}", e)))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(Self::AccountsChanged)
+            }
+            "chainChanged" => parse_chain_id(payload).map(Self::ChainChanged),
+            "connect" => {
+                let chain_id = get_field(payload, "chainId")?;
+                parse_chain_id(&chain_id).map(|chain_id| Self::Connect { chain_id })
+            }
+            "disconnect" => Ok(Self::Disconnect(Eip1193Error::from_js_value(payload.clone()))),
+            "message" => {
+                let r#type = get_field(payload, "type")?
+                    .as_string()
+                    .ok_or_else(|| Eip1193Error::SerializationError("Message type is not a string".into()))?;
+                let data = get_field(payload, "data")?;
+
+                // `JSON.stringify(undefined)` yields undefined rather than a string
+                let data = match js_sys::JSON::stringify(&data).ok().and_then(|s| s.as_string()) {
+                    Some(json) => serde_json::from_str(&json)?,
+                    None => serde_json::Value::Null,
+                };
+
+                Ok(Self::Message { r#type, data })
+            }
+            other => Err(Eip1193Error::SerializationError(format!("Unknown provider event: {}", other))),
+        }
+    }
+}
+
+/// Read `payload[field]`
+fn get_field(payload: &JsValue, field: &str) -> Result<JsValue, Eip1193Error> {
+    js_sys::Reflect::get(payload, &field.into()).map_err(Eip1193Error::from_js_value)
+}
+
+/// Parse a hex chain ID (e.g., `"0x1"`) as sent by EIP-1193 providers
+fn parse_chain_id(value: &JsValue) -> Result<u64, Eip1193Error> {
+    let chain_id = value
+        .as_string()
+        .ok_or_else(|| Eip1193Error::SerializationError("Chain ID is not a string".into()))?;
+
+    u64::from_str_radix(chain_id.trim_start_matches("0x"), 16)
+        .map_err(|e| Eip1193Error::SerializationError(format!("Invalid chain ID {}: {}", chain_id, e)))
+}
+
+impl Eip1193Transport {
+    /// Call `handler` for every EIP-1193 event emitted by the provider
+    ///
+    /// Payloads that fail to parse are logged and skipped. The listeners stay registered
+    /// until the returned subscriptions are dropped.
+    pub fn on_events<F>(&self, handler: F) -> Result<Vec<Subscription>, Eip1193Error>
+    where
+        F: Fn(ProviderEvent) + 'static,
+    {
+        let handler = Rc::new(handler);
+
+        ProviderEvent::NAMES
+            .iter()
+            .map(|&event| {
+                let handler = handler.clone();
+                self.on(event, move |payload| match ProviderEvent::parse(event, &payload) {
+                    Ok(parsed) => handler(parsed),
+                    Err(e) => log::error!("Failed to parse {} event: {}", event, e),
+                })
+            })
+            .collect()
+    }
+
+    /// Stream of typed EIP-1193 events emitted by the provider
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// use alloy_eip1193::{Eip1193Transport, ProviderEvent};
+    /// use futures_util::StreamExt;
+    ///
+    /// let mut events = transport.events()?;
+    /// while let Some(event) = events.next().await {
+    ///     if let ProviderEvent::ChainChanged(chain_id) = event {
+    ///         log::info!("Now on chain {}", chain_id);
+    ///     }
+    /// }
+    /// ```
+    pub fn events(&self) -> Result<ProviderEventStream, Eip1193Error> {
+        let (sender, receiver) = mpsc::unbounded();
+        let subscriptions = self.on_events(move |event| {
+            // The receiver is gone once the stream is dropped, along with these listeners
+            let _ = sender.unbounded_send(event);
+        })?;
+
+        Ok(ProviderEventStream { receiver, _subscriptions: subscriptions })
+    }
+}
+
+/// Stream of [`ProviderEvent`]s created by [`Eip1193Transport::events`]
+///
+/// The event listeners are removed from the provider when the stream is dropped.
+#[must_use = "streams do nothing unless polled"]
+#[derive(Debug)]
+pub struct ProviderEventStream {
+    receiver: UnboundedReceiver<ProviderEvent>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl Stream for ProviderEventStream {
+    type Item = ProviderEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_next_unpin(cx)
+    }
+}
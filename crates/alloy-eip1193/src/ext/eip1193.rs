@@ -10,6 +10,9 @@ use alloy::network::Network;
 use alloy::transports::{TransportResult, TransportErrorKind};
 use async_trait::async_trait;
 use crate::chain::ChainConfig;
+use crate::ext::eip2255::{Permission, PermissionRequest};
+use crate::ext::eip747::WatchAssetParams;
+use crate::ext::eip5792::{CallsStatus, SendCallsRequest, SendCallsResult, WalletCapabilities};
 
 /// EIP-1193 Provider Extension
 ///
@@ -23,7 +26,18 @@ use crate::chain::ChainConfig;
 /// - `eth_requestAccounts` - Request wallet accounts (user consent)
 /// - `wallet_switchEthereumChain` - Switch active blockchain network
 /// - `wallet_addEthereumChain` - Add a new blockchain network to wallet
-/// - `wallet_watchAsset` - Request wallet to track a token
+/// - `wallet_watchAsset` - Request wallet to track a token or NFT (EIP-747)
+///
+/// The EIP-2255 permission methods:
+/// - `wallet_requestPermissions` - Request permissions (prompts the user)
+/// - `wallet_getPermissions` - Get the permissions granted to the site
+/// - `wallet_revokePermissions` - Give up permissions, e.g. account access on disconnect
+///
+/// And the EIP-5792 wallet call API:
+/// - `wallet_getCapabilities` - Query what the wallet supports on each chain
+/// - `wallet_sendCalls` - Send a batch of calls
+/// - `wallet_getCallsStatus` - Get the status of a batch
+/// - `wallet_showCallsStatus` - Show the status of a batch in the wallet UI
 ///
 /// # Example
 ///
@@ -144,44 +158,43 @@ pub trait Eip1193<N: Network>: Send + Sync {
     /// ```
     async fn add_chain(&self, config: ChainConfig) -> TransportResult<()>;
 
-    /// Request wallet to track a token
+    /// Request wallet to track a token or NFT
     ///
-    /// This method implements `wallet_watchAsset` which requests that the wallet
-    /// tracks the specified token. This allows users to view their token balance
+    /// This method implements EIP-747 `wallet_watchAsset` which requests that the
+    /// wallet tracks the specified asset. This allows users to view their balance
     /// in their wallet UI.
     ///
-    /// # EIP-1193 Specification
+    /// # EIP-747 Specification
     ///
     /// - Method: `wallet_watchAsset`
-    /// - Parameters: `[{ type: "ERC20", options: { address, symbol?, decimals?, image? } }]`
+    /// - Parameters: `{ type: "ERC20", options: { address, symbol, decimals, image? } }`,
+    ///   or `{ type: "ERC721" | "ERC1155", options: { address, tokenId } }`
     /// - Returns: `true` if added, `false` if rejected
     ///
     /// # Arguments
     ///
-    /// * `token` - Token contract address
-    /// * `symbol` - Token symbol (e.g., "USDC")
-    /// * `decimals` - Token decimals (usually 18 for ERC20)
+    /// * `asset` - Token or NFT to track, see [`WatchAssetParams`]
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - Invalid token address
-    /// - Wallet doesn't support `wallet_watchAsset`
+    /// - Wallet doesn't support `wallet_watchAsset`, or NFTs
+    /// - Wallet can't verify the NFT is owned by the connected account
     ///
     /// # Example
     ///
     /// ```rust,ignore
+    /// use alloy_eip1193::ext::WatchAssetParams;
+    ///
     /// // Add USDC to wallet
     /// let usdc = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".parse()?;
-    /// let added = provider.watch_asset(usdc, "USDC", 6).await?;
+    /// let asset = WatchAssetParams::erc20(usdc, "USDC", 6)
+    ///     .with_image("https://example.com/usdc.png");
+    /// let added = provider.watch_asset(asset).await?;
     /// assert!(added);
     /// ```
-    async fn watch_asset(
-        &self,
-        token: Address,
-        symbol: &str,
-        decimals: u8,
-    ) -> TransportResult<bool>;
+    async fn watch_asset(&self, asset: WatchAssetParams) -> TransportResult<bool>;
 
     /// Get currently connected accounts (without prompting)
     ///
@@ -211,6 +224,171 @@ pub trait Eip1193<N: Network>: Send + Sync {
     /// }
     /// ```
     async fn accounts(&self) -> TransportResult<Vec<Address>>;
+
+    /// Request permissions from the wallet (prompts the user)
+    ///
+    /// This method implements EIP-2255 `wallet_requestPermissions`. Requesting
+    /// `eth_accounts` lets the user pick accounts again even if the site is
+    /// already connected.
+    ///
+    /// # EIP-2255 Specification
+    ///
+    /// - Method: `wallet_requestPermissions`
+    /// - Parameters: `[{ "<method>": {} }]`
+    /// - Returns: Array of granted permissions
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - User rejects the request (EIP-1193 error code 4001)
+    /// - Wallet doesn't support EIP-2255 (error code 4200 or -32601)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use alloy_eip1193::ext::PermissionRequest;
+    ///
+    /// let permissions = provider.request_permissions(PermissionRequest::eth_accounts()).await?;
+    /// let accounts = permissions.iter().find_map(|permission| permission.accounts());
+    /// ```
+    async fn request_permissions(&self, request: PermissionRequest) -> TransportResult<Vec<Permission>>;
+
+    /// Get the permissions the site has been granted (without prompting)
+    ///
+    /// This method implements EIP-2255 `wallet_getPermissions`.
+    ///
+    /// # EIP-2255 Specification
+    ///
+    /// - Method: `wallet_getPermissions`
+    /// - Parameters: None
+    /// - Returns: Array of granted permissions
+    async fn get_permissions(&self) -> TransportResult<Vec<Permission>>;
+
+    /// Give up permissions the site has been granted
+    ///
+    /// This method implements `wallet_revokePermissions`, the revocation counterpart
+    /// of EIP-2255 proposed in EIP-7715 and supported by MetaMask. Revoking
+    /// `eth_accounts` disconnects the site, so `eth_accounts` returns no account
+    /// until the user connects again.
+    ///
+    /// # Specification
+    ///
+    /// - Method: `wallet_revokePermissions`
+    /// - Parameters: `[{ "<method>": {} }]`
+    /// - Returns: `null`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Wallet doesn't support revoking permissions (error code 4200 or -32601)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// provider.revoke_permissions(PermissionRequest::eth_accounts()).await?;
+    /// ```
+    async fn revoke_permissions(&self, request: PermissionRequest) -> TransportResult<()>;
+
+    /// Get the capabilities the wallet supports for an account
+    ///
+    /// This method implements EIP-5792 `wallet_getCapabilities`.
+    ///
+    /// # EIP-5792 Specification
+    ///
+    /// - Method: `wallet_getCapabilities`
+    /// - Parameters: `[address, chainIds?]`
+    /// - Returns: Capabilities keyed by hex chain ID
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - Account to query the capabilities of
+    /// * `chain_ids` - Chains to query; all chains the wallet supports if empty
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Wallet doesn't support EIP-5792 (error code 4200)
+    /// - Account is not authorized (error code 4100)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let capabilities = provider.get_capabilities(account, &[8453]).await?;
+    /// if capabilities.supports_atomic(8453) {
+    ///     // Batch approve + swap
+    /// }
+    /// ```
+    async fn get_capabilities(&self, account: Address, chain_ids: &[u64]) -> TransportResult<WalletCapabilities>;
+
+    /// Send a batch of calls
+    ///
+    /// This method implements EIP-5792 `wallet_sendCalls`. The wallet may execute the
+    /// calls as a single atomic transaction or, unless `atomic_required` is set, as
+    /// several transactions.
+    ///
+    /// # EIP-5792 Specification
+    ///
+    /// - Method: `wallet_sendCalls`
+    /// - Parameters: `[{ version, id?, from?, chainId, atomicRequired, calls, capabilities? }]`
+    /// - Returns: `{ id, capabilities? }`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - User rejects the request (EIP-1193 error code 4001)
+    /// - Wallet doesn't support EIP-5792 (error code 4200)
+    /// - Atomic execution is required but unsupported (EIP-5792 error code 5760)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use alloy_eip1193::ext::{Call, SendCallsRequest};
+    ///
+    /// let request = SendCallsRequest::new(8453, vec![
+    ///     Call::new(token).with_data(approve_calldata),
+    ///     Call::new(router).with_data(swap_calldata),
+    /// ])
+    /// .with_atomic_required(true);
+    ///
+    /// let result = provider.send_calls(request).await?;
+    /// ```
+    async fn send_calls(&self, request: SendCallsRequest) -> TransportResult<SendCallsResult>;
+
+    /// Get the status of a batch sent with `send_calls`
+    ///
+    /// This method implements EIP-5792 `wallet_getCallsStatus`.
+    ///
+    /// # EIP-5792 Specification
+    ///
+    /// - Method: `wallet_getCallsStatus`
+    /// - Parameters: `[id]`
+    /// - Returns: `{ version, id, chainId, status, atomic, receipts?, capabilities? }`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The batch ID is unknown (EIP-5792 error code 5730)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let status = provider.get_calls_status(&result.id).await?;
+    /// if status.is_final() {
+    ///     println!("Batch settled: {:?}", status.state());
+    /// }
+    /// ```
+    async fn get_calls_status(&self, id: &str) -> TransportResult<CallsStatus>;
+
+    /// Ask the wallet to show the status of a batch sent with `send_calls`
+    ///
+    /// This method implements EIP-5792 `wallet_showCallsStatus`.
+    ///
+    /// # EIP-5792 Specification
+    ///
+    /// - Method: `wallet_showCallsStatus`
+    /// - Parameters: `[id]`
+    /// - Returns: `null`
+    async fn show_calls_status(&self, id: &str) -> TransportResult<()>;
 }
 
 /// Blanket implementation for any Provider
@@ -284,22 +462,9 @@ where
             .await
     }
 
-    async fn watch_asset(
-        &self,
-        token: Address,
-        symbol: &str,
-        decimals: u8,
-    ) -> TransportResult<bool> {
-        let params = serde_json::json!([{
-            "type": "ERC20",
-            "options": {
-                "address": format!("{:?}", token),
-                "symbol": symbol,
-                "decimals": decimals,
-            }
-        }]);
-
-        self.client().request("wallet_watchAsset", params).await
+    async fn watch_asset(&self, asset: WatchAssetParams) -> TransportResult<bool> {
+        // EIP-747 takes the asset itself as params, not an array
+        self.client().request("wallet_watchAsset", asset).await
     }
 
     async fn accounts(&self) -> TransportResult<Vec<Address>> {
@@ -317,4 +482,44 @@ where
             })
             .collect()
     }
+
+    async fn request_permissions(&self, request: PermissionRequest) -> TransportResult<Vec<Permission>> {
+        self.client().request("wallet_requestPermissions", (request,)).await
+    }
+
+    async fn get_permissions(&self) -> TransportResult<Vec<Permission>> {
+        self.client().request("wallet_getPermissions", ()).await
+    }
+
+    async fn revoke_permissions(&self, request: PermissionRequest) -> TransportResult<()> {
+        self.client().request("wallet_revokePermissions", (request,)).await
+    }
+
+    async fn get_capabilities(&self, account: Address, chain_ids: &[u64]) -> TransportResult<WalletCapabilities> {
+        let account = format!("{:?}", account);
+
+        if chain_ids.is_empty() {
+            return self.client().request("wallet_getCapabilities", (account,)).await;
+        }
+
+        let chain_ids: Vec<String> = chain_ids.iter()
+            .map(|chain_id| format!("0x{:x}", chain_id))
+            .collect();
+
+        self.client()
+            .request("wallet_getCapabilities", (account, chain_ids))
+            .await
+    }
+
+    async fn send_calls(&self, request: SendCallsRequest) -> TransportResult<SendCallsResult> {
+        self.client().request("wallet_sendCalls", (request,)).await
+    }
+
+    async fn get_calls_status(&self, id: &str) -> TransportResult<CallsStatus> {
+        self.client().request("wallet_getCallsStatus", (id,)).await
+    }
+
+    async fn show_calls_status(&self, id: &str) -> TransportResult<()> {
+        self.client().request("wallet_showCallsStatus", (id,)).await
+    }
 }
@@ -0,0 +1,103 @@
+//! EIP-2255 Wallet Permissions Types
+//!
+//! Types returned by `wallet_requestPermissions` and `wallet_getPermissions`, plus
+//! the request format shared with `wallet_revokePermissions`.
+
+use alloy::primitives::Address;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Permission to call `eth_accounts`, i.e. to see the user's accounts
+pub const ETH_ACCOUNTS: &str = "eth_accounts";
+
+/// Caveat type wallets use to list the accounts an `eth_accounts` permission exposes
+pub const RESTRICT_RETURNED_ACCOUNTS: &str = "restrictReturnedAccounts";
+
+/// A permission the user granted to the site
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Permission {
+    /// Origin of the site the permission was granted to
+    pub invoker: String,
+    /// RPC method the permission allows, e.g. [`ETH_ACCOUNTS`]
+    pub parent_capability: String,
+    /// Restrictions on the permission
+    #[serde(default)]
+    pub caveats: Vec<Caveat>,
+    /// Wallet-specific identifier of the permission
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Unix time in milliseconds the permission was granted at
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<u64>,
+}
+
+impl Permission {
+    /// Accounts an `eth_accounts` permission is restricted to
+    ///
+    /// Returns `None` for other permissions or if the wallet doesn't list them.
+    pub fn accounts(&self) -> Option<Vec<Address>> {
+        if self.parent_capability != ETH_ACCOUNTS {
+            return None;
+        }
+
+        self.caveats.iter()
+            .find(|caveat| caveat.r#type == RESTRICT_RETURNED_ACCOUNTS)
+            .and_then(|caveat| serde_json::from_value(caveat.value.clone()).ok())
+    }
+}
+
+/// A restriction on a permission
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Caveat {
+    /// Kind of restriction, e.g. [`RESTRICT_RETURNED_ACCOUNTS`]
+    pub r#type: String,
+    /// Restriction value; its format depends on `type`
+    pub value: serde_json::Value,
+}
+
+/// Permissions to request or revoke, by RPC method name
+///
+/// Serializes as `{ "<method>": {} }`; caveats are left for the wallet to choose.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PermissionRequest(pub HashMap<String, HashMap<String, serde_json::Value>>);
+
+impl PermissionRequest {
+    /// Request for each of `methods`
+    pub fn new<'a>(methods: impl IntoIterator<Item = &'a str>) -> Self {
+        Self(methods.into_iter().map(|method| (method.to_string(), HashMap::new())).collect())
+    }
+
+    /// Request for [`ETH_ACCOUNTS`] only
+    pub fn eth_accounts() -> Self {
+        Self::new([ETH_ACCOUNTS])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permission_accounts() {
+        let account = Address::repeat_byte(0x11);
+        let permission: Permission = serde_json::from_value(serde_json::json!({
+            "invoker": "https://app.example",
+            "parentCapability": "eth_accounts",
+            "caveats": [{ "type": "restrictReturnedAccounts", "value": [format!("{:?}", account)] }],
+            "id": "1",
+            "date": 1700000000000u64
+        }))
+        .unwrap();
+
+        assert_eq!(permission.accounts(), Some(vec![account]));
+    }
+
+    #[test]
+    fn test_serialize_permission_request() {
+        assert_eq!(
+            serde_json::to_value(PermissionRequest::eth_accounts()).unwrap(),
+            serde_json::json!({ "eth_accounts": {} })
+        );
+    }
+}
@@ -0,0 +1,380 @@
+//! EIP-5792 Wallet Call API Types
+//!
+//! Request and response types for `wallet_getCapabilities`, `wallet_sendCalls`,
+//! `wallet_getCallsStatus` and `wallet_showCallsStatus`, following version 2.0.0
+//! of the specification.
+
+use alloy::primitives::{Address, Bytes, Log, TxHash, B256, U256};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Version of the EIP-5792 request format sent with `wallet_sendCalls`
+pub const SEND_CALLS_VERSION: &str = "2.0.0";
+
+/// Capabilities the wallet supports, per chain, as returned by `wallet_getCapabilities`
+///
+/// The wallet keys its response by hex chain ID; those keys are parsed into numbers.
+/// A `0x0` key holds capabilities supported on every chain.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "HashMap<String, ChainCapabilities>", into = "HashMap<String, ChainCapabilities>")]
+pub struct WalletCapabilities(pub HashMap<u64, ChainCapabilities>);
+
+impl WalletCapabilities {
+    /// Capabilities on `chain_id`, falling back to those supported on every chain
+    pub fn for_chain(&self, chain_id: u64) -> Option<&ChainCapabilities> {
+        self.0.get(&chain_id).or_else(|| self.0.get(&0))
+    }
+
+    /// Check if the wallet can execute a batch atomically on `chain_id`
+    ///
+    /// Also true when the wallet can only do so after upgrading the account.
+    pub fn supports_atomic(&self, chain_id: u64) -> bool {
+        self.for_chain(chain_id)
+            .and_then(|capabilities| capabilities.atomic.as_ref())
+            .is_some_and(|atomic| atomic.status != AtomicStatus::Unsupported)
+    }
+
+    /// Check if the wallet can sponsor gas through a paymaster on `chain_id`
+    pub fn supports_paymaster(&self, chain_id: u64) -> bool {
+        self.for_chain(chain_id)
+            .and_then(|capabilities| capabilities.paymaster_service.as_ref())
+            .is_some_and(|paymaster| paymaster.supported)
+    }
+}
+
+impl TryFrom<HashMap<String, ChainCapabilities>> for WalletCapabilities {
+    type Error = String;
+
+    fn try_from(map: HashMap<String, ChainCapabilities>) -> Result<Self, Self::Error> {
+        map.into_iter()
+            .map(|(key, capabilities)| {
+                u64::from_str_radix(key.trim_start_matches("0x"), 16)
+                    .map(|chain_id| (chain_id, capabilities))
+                    .map_err(|e| format!("Invalid chain ID {}: {}", key, e))
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl From<WalletCapabilities> for HashMap<String, ChainCapabilities> {
+    fn from(capabilities: WalletCapabilities) -> Self {
+        capabilities.0.into_iter()
+            .map(|(chain_id, capabilities)| (format!("0x{:x}", chain_id), capabilities))
+            .collect()
+    }
+}
+
+/// Capabilities the wallet supports on one chain
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainCapabilities {
+    /// Whether batches can be executed atomically
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub atomic: Option<AtomicCapability>,
+    /// Whether gas can be sponsored through an ERC-7677 paymaster
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paymaster_service: Option<PaymasterServiceCapability>,
+    /// Capabilities without a dedicated type, by name
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+/// The `atomic` capability
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AtomicCapability {
+    /// Whether the wallet executes batches atomically on this chain
+    pub status: AtomicStatus,
+}
+
+/// Atomic execution support of a wallet on a chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AtomicStatus {
+    /// Batches are executed atomically
+    Supported,
+    /// The wallet can upgrade the account to execute batches atomically, with the user's approval
+    Ready,
+    /// Batches are not executed atomically
+    Unsupported,
+}
+
+/// The `paymasterService` capability as reported by `wallet_getCapabilities`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaymasterServiceCapability {
+    /// Whether the wallet accepts a paymaster service URL on this chain
+    pub supported: bool,
+}
+
+/// Capabilities requested for a batch or a single call
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestCapabilities {
+    /// Sponsor gas through an ERC-7677 paymaster
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub paymaster_service: Option<PaymasterService>,
+    /// Capabilities without a dedicated type, by name
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
+
+/// Paymaster to sponsor gas with
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaymasterService {
+    /// ERC-7677 paymaster service URL
+    pub url: String,
+    /// Let wallets without paymaster support send the batch anyway
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+}
+
+/// A call in a `wallet_sendCalls` batch
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Call {
+    /// Target address; `None` deploys a contract
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<Address>,
+    /// Calldata
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Bytes>,
+    /// Value in wei
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<U256>,
+    /// Capabilities for this call only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<RequestCapabilities>,
+}
+
+impl Call {
+    /// Call to `to` without data or value
+    pub fn new(to: Address) -> Self {
+        Self {
+            to: Some(to),
+            ..Default::default()
+        }
+    }
+
+    /// Set the calldata
+    pub fn with_data(mut self, data: impl Into<Bytes>) -> Self {
+        self.data = Some(data.into());
+        self
+    }
+
+    /// Set the value in wei
+    pub fn with_value(mut self, value: U256) -> Self {
+        self.value = Some(value);
+        self
+    }
+}
+
+/// Parameters of `wallet_sendCalls`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendCallsRequest {
+    /// Request format version, see [`SEND_CALLS_VERSION`]
+    pub version: String,
+    /// Identifier for the batch; the wallet picks one if `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Account to send from; the wallet picks one if `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<Address>,
+    /// Chain to send the calls on
+    #[serde(with = "alloy::serde::quantity")]
+    pub chain_id: u64,
+    /// Fail rather than execute the calls non-atomically
+    pub atomic_required: bool,
+    /// Calls to execute, in order
+    pub calls: Vec<Call>,
+    /// Capabilities for the whole batch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<RequestCapabilities>,
+}
+
+impl SendCallsRequest {
+    /// Batch of `calls` on `chain_id`, which may be executed non-atomically
+    pub fn new(chain_id: u64, calls: Vec<Call>) -> Self {
+        Self {
+            version: SEND_CALLS_VERSION.to_string(),
+            id: None,
+            from: None,
+            chain_id,
+            atomic_required: false,
+            calls,
+            capabilities: None,
+        }
+    }
+
+    /// Set the account to send from
+    pub fn with_from(mut self, from: Address) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    /// Require the calls to be executed atomically
+    pub fn with_atomic_required(mut self, atomic_required: bool) -> Self {
+        self.atomic_required = atomic_required;
+        self
+    }
+
+    /// Sponsor gas through the ERC-7677 paymaster at `url`
+    pub fn with_paymaster(mut self, url: impl Into<String>) -> Self {
+        self.capabilities.get_or_insert_with(Default::default).paymaster_service = Some(PaymasterService {
+            url: url.into(),
+            optional: false,
+        });
+        self
+    }
+}
+
+/// Response of `wallet_sendCalls`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SendCallsResult {
+    /// Identifier to pass to `wallet_getCallsStatus`
+    pub id: String,
+    /// Capability-specific information about the batch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<HashMap<String, serde_json::Value>>,
+}
+
+/// Response of `wallet_getCallsStatus`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallsStatus {
+    /// Response format version
+    pub version: String,
+    /// Identifier of the batch
+    pub id: String,
+    /// Chain the batch was sent on
+    #[serde(with = "alloy::serde::quantity")]
+    pub chain_id: u64,
+    /// Status code, see [`CallsStatus::state`]
+    pub status: u16,
+    /// Whether the calls were executed atomically
+    pub atomic: bool,
+    /// Receipts of the transactions the calls were included in
+    #[serde(default)]
+    pub receipts: Option<Vec<CallReceipt>>,
+    /// Capability-specific information about the batch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<HashMap<String, serde_json::Value>>,
+}
+
+impl CallsStatus {
+    /// Interpret the status code
+    pub fn state(&self) -> CallsState {
+        CallsState::from_code(self.status)
+    }
+
+    /// Check if the status will no longer change
+    pub fn is_final(&self) -> bool {
+        self.state() != CallsState::Pending
+    }
+}
+
+/// Meaning of a `wallet_getCallsStatus` status code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallsState {
+    /// Not included onchain yet (1xx)
+    Pending,
+    /// Included onchain without reverts (2xx)
+    Confirmed,
+    /// Not included onchain, and the wallet won't retry (4xx)
+    Failed,
+    /// Reverted completely, with only changes like gas payment applied (5xx)
+    Reverted,
+    /// Reverted partially, with some changes applied onchain (6xx)
+    PartiallyReverted,
+}
+
+impl CallsState {
+    /// State for a status code, by its hundreds
+    ///
+    /// Unknown codes count as failures so callers stop polling.
+    pub fn from_code(code: u16) -> Self {
+        match code / 100 {
+            1 => Self::Pending,
+            2 => Self::Confirmed,
+            5 => Self::Reverted,
+            6 => Self::PartiallyReverted,
+            _ => Self::Failed,
+        }
+    }
+}
+
+/// Receipt of a transaction a batch was included in
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallReceipt {
+    /// Logs emitted by the calls in this transaction
+    pub logs: Vec<Log>,
+    /// `1` for success, `0` for failure
+    #[serde(with = "alloy::serde::quantity")]
+    pub status: u64,
+    /// Hash of the block the transaction was included in
+    pub block_hash: B256,
+    /// Number of the block the transaction was included in
+    #[serde(with = "alloy::serde::quantity")]
+    pub block_number: u64,
+    /// Gas used by the transaction
+    #[serde(with = "alloy::serde::quantity")]
+    pub gas_used: u64,
+    /// Hash of the transaction
+    pub transaction_hash: TxHash,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_capabilities() {
+        let capabilities: WalletCapabilities = serde_json::from_value(serde_json::json!({
+            "0x2105": {
+                "atomic": { "status": "supported" },
+                "paymasterService": { "supported": true },
+                "flowControl": { "supported": true }
+            },
+            "0x1": {
+                "atomic": { "status": "ready" }
+            }
+        }))
+        .unwrap();
+
+        assert!(capabilities.supports_atomic(8453));
+        assert!(capabilities.supports_paymaster(8453));
+        assert!(capabilities.supports_atomic(1));
+        assert!(!capabilities.supports_paymaster(1));
+        assert!(!capabilities.supports_atomic(10));
+        assert!(capabilities.for_chain(8453).unwrap().other.contains_key("flowControl"));
+    }
+
+    #[test]
+    fn test_serialize_send_calls_request() {
+        let to = Address::repeat_byte(0x11);
+        let request = SendCallsRequest::new(8453, vec![Call::new(to).with_value(U256::from(1))])
+            .with_atomic_required(true)
+            .with_paymaster("https://paymaster.example");
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "version": "2.0.0",
+                "chainId": "0x2105",
+                "atomicRequired": true,
+                "calls": [{ "to": format!("{:?}", to), "value": "0x1" }],
+                "capabilities": { "paymasterService": { "url": "https://paymaster.example" } }
+            })
+        );
+    }
+
+    #[test]
+    fn test_calls_state() {
+        assert_eq!(CallsState::from_code(100), CallsState::Pending);
+        assert_eq!(CallsState::from_code(200), CallsState::Confirmed);
+        assert_eq!(CallsState::from_code(400), CallsState::Failed);
+        assert_eq!(CallsState::from_code(500), CallsState::Reverted);
+        assert_eq!(CallsState::from_code(600), CallsState::PartiallyReverted);
+        assert_eq!(CallsState::from_code(999), CallsState::Failed);
+    }
+}
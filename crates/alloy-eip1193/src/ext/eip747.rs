@@ -0,0 +1,140 @@
+//! EIP-747 Watch Asset Types
+//!
+//! Parameters of `wallet_watchAsset` for fungible tokens and, where the wallet
+//! supports them, ERC-721 and ERC-1155 NFTs.
+
+use alloy::primitives::{Address, U256};
+use serde::{Serialize, Serializer};
+
+/// Asset to ask the wallet to track
+///
+/// Serializes as `{ type, options }`, the `wallet_watchAsset` parameter.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let usdc = WatchAssetParams::erc20(usdc_address, "USDC", 6)
+///     .with_image("https://example.com/usdc.png");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "options")]
+pub enum WatchAssetParams {
+    /// ERC-20 token
+    #[serde(rename = "ERC20")]
+    Erc20(Erc20Asset),
+    /// ERC-721 NFT
+    #[serde(rename = "ERC721")]
+    Erc721(NftAsset),
+    /// ERC-1155 token
+    #[serde(rename = "ERC1155")]
+    Erc1155(NftAsset),
+}
+
+impl WatchAssetParams {
+    /// ERC-20 token without an image
+    pub fn erc20(address: Address, symbol: impl Into<String>, decimals: u8) -> Self {
+        Self::Erc20(Erc20Asset {
+            address,
+            symbol: symbol.into(),
+            decimals,
+            image: None,
+        })
+    }
+
+    /// ERC-721 NFT `token_id` of collection `address`
+    pub fn erc721(address: Address, token_id: U256) -> Self {
+        Self::Erc721(NftAsset { address, token_id })
+    }
+
+    /// ERC-1155 token `token_id` of contract `address`
+    pub fn erc1155(address: Address, token_id: U256) -> Self {
+        Self::Erc1155(NftAsset { address, token_id })
+    }
+
+    /// Set the image wallets show for an ERC-20 token
+    ///
+    /// NFT images come from their metadata, so this has no effect on them.
+    pub fn with_image(mut self, image: impl Into<String>) -> Self {
+        if let Self::Erc20(asset) = &mut self {
+            asset.image = Some(image.into());
+        }
+        self
+    }
+
+    /// Contract address of the asset
+    pub fn address(&self) -> Address {
+        match self {
+            Self::Erc20(asset) => asset.address,
+            Self::Erc721(asset) | Self::Erc1155(asset) => asset.address,
+        }
+    }
+}
+
+/// ERC-20 token options
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Erc20Asset {
+    /// Token contract address
+    pub address: Address,
+    /// Ticker symbol, up to 11 characters in most wallets
+    pub symbol: String,
+    /// Token decimals
+    pub decimals: u8,
+    /// URL of the token image; HTTP(S) and data URIs are widely supported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+}
+
+/// ERC-721 or ERC-1155 token options
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NftAsset {
+    /// NFT contract address
+    pub address: Address,
+    /// Token ID, sent as a decimal string
+    #[serde(serialize_with = "serialize_decimal")]
+    pub token_id: U256,
+}
+
+/// Wallets expect token IDs as decimal strings, while `U256` serializes as hex
+fn serialize_decimal<S: Serializer>(value: &U256, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_erc20() {
+        let address = Address::repeat_byte(0x11);
+        let params = WatchAssetParams::erc20(address, "USDC", 6).with_image("https://example.com/usdc.png");
+
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            serde_json::json!({
+                "type": "ERC20",
+                "options": {
+                    "address": format!("{:?}", address),
+                    "symbol": "USDC",
+                    "decimals": 6,
+                    "image": "https://example.com/usdc.png"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_serialize_nft() {
+        let address = Address::repeat_byte(0x22);
+        let params = WatchAssetParams::erc721(address, U256::from(1234));
+
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            serde_json::json!({
+                "type": "ERC721",
+                "options": { "address": format!("{:?}", address), "tokenId": "1234" }
+            })
+        );
+        assert_eq!(params.clone().with_image("ignored"), params);
+    }
+}
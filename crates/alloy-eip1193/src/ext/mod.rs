@@ -4,5 +4,25 @@
 //! as ergonomic APIs on Alloy providers.
 
 mod eip1193;
+pub mod eip2255;
+pub mod eip747;
+pub mod eip5792;
 
 pub use eip1193::Eip1193;
+pub use eip2255::{Permission, Caveat, PermissionRequest};
+pub use eip747::{WatchAssetParams, Erc20Asset, NftAsset};
+pub use eip5792::{
+    WalletCapabilities,
+    ChainCapabilities,
+    AtomicCapability,
+    AtomicStatus,
+    PaymasterServiceCapability,
+    RequestCapabilities,
+    PaymasterService,
+    Call,
+    SendCallsRequest,
+    SendCallsResult,
+    CallsStatus,
+    CallsState,
+    CallReceipt,
+};
@@ -7,8 +7,10 @@
 //! ## Components
 //!
 //! - **`Eip1193Transport`**: Tower Service implementation for JSON-RPC requests via browser wallets
-//! - **`WalletLayer`**: Provider layer for smart request routing
-//! - **`Eip1193Signer`**: Signer implementation (⚠️ uses eth_sign, shows warnings)
+//! - **`Subscription`**: EIP-1193 event listener handle, removed from the provider on drop
+//! - **`ProviderEvent`**: Typed EIP-1193 events, available as a `Stream` via `Eip1193Transport::events`
+//! - **`WalletLayer`**: Provider layer routing wallet methods to the browser wallet by name
+//! - **`Eip1193Signer`**: Signer implementation (signs transactions with `eth_signTransaction`)
 //! - **`ext::Eip1193`**: Trait extension for EIP-1193 mandated wallet operations (automatically available on any provider)
 //!
 //! ## Usage Patterns
@@ -50,7 +52,7 @@
 //! provider.switch_chain(137).await?;
 //! ```
 //!
-//! ### Pattern 3: With Signer (Caveat: Shows Warnings)
+//! ### Pattern 3: With Signer (Caveat: Wallet Support Varies)
 //!
 //! Use `Eip1193Signer` when you need full NetworkWallet compatibility:
 //!
@@ -60,9 +62,9 @@
 //!
 //! let signer = Eip1193Signer::from_window().await?;
 //!
-//! // ⚠️ WARNING: This uses eth_sign internally
-//! // MetaMask will show scary warnings to users
-//! // Only use if you need signing without broadcasting
+//! // Transactions are signed with eth_signTransaction, which e.g. Frame and
+//! // Rabby support but MetaMask doesn't. Only use if you need signing
+//! // without broadcasting
 //! let provider = ProviderBuilder::new()
 //!     .wallet(signer)
 //!     .on_http(rpc_url);
@@ -75,12 +77,16 @@ mod transport;
 mod signer;
 mod chain;
 mod error;
+mod subscription;
+mod events;
 
 pub use transport::Eip1193Transport;
 pub use signer::Eip1193Signer;
 pub use chain::ChainConfig;
 pub use provider::{WalletLayer, WalletProvider};
 pub use error::Eip1193Error;
+pub use subscription::Subscription;
+pub use events::{ProviderEvent, ProviderEventStream};
 
 // Re-export provider module for docs
 pub mod provider;
@@ -100,6 +106,8 @@ pub mod prelude {
         Eip1193Signer,
         ChainConfig,
         Eip1193Error,
+        Subscription,
+        ProviderEvent,
     };
     pub use crate::ext::Eip1193;
     pub use alloy::primitives::{Address, Signature, B256};
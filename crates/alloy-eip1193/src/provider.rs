@@ -5,23 +5,57 @@
 
 use alloy::providers::{Provider, ProviderBuilder, ProviderLayer, RootProvider, PendingTransactionBuilder, SendableTx};
 use alloy::network::{Network, TransactionBuilder};
-use alloy::transports::TransportResult;
+use alloy::rpc::client::RpcClient;
+use alloy::transports::{BoxTransport, TransportError, TransportFut, TransportResult};
 use alloy::primitives::TxHash;
+use alloy_json_rpc::{ErrorPayload, Id, RequestPacket, Response, ResponsePacket, ResponsePayload, RpcError, SerializedRequest};
+use futures_util::future::{join, join_all};
 use std::marker::PhantomData;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower::Service;
 use wasm_bindgen::JsValue;
 use crate::{Eip1193Transport, Eip1193Error};
 
+/// Methods `WalletLayer` sends to the wallet unless configured otherwise
+///
+/// Entries ending in `*` match every method starting with the rest of the entry.
+pub const DEFAULT_WALLET_METHODS: &[&str] = &[
+    "eth_accounts",
+    "eth_requestAccounts",
+    "eth_sendTransaction",
+    "eth_signTransaction",
+    "eth_sign",
+    "personal_sign",
+    "eth_signTypedData",
+    "eth_signTypedData_v3",
+    "eth_signTypedData_v4",
+    "wallet_*",
+];
+
 /// Layer that adds EIP-1193 wallet routing to any provider
 ///
-/// Routes wallet operations to browser wallet, everything else to original transport
+/// Routes wallet operations to browser wallet, everything else to original transport.
+/// Requests are routed by method name, so raw `client().request(..)` calls are routed
+/// the same way as provider methods.
+///
+/// # Example
+/// ```rust,ignore
+/// // Also send a wallet-specific method to the wallet
+/// let layer = WalletLayer::from_window()?.with_wallet_method("snap_*");
+/// ```
 pub struct WalletLayer {
     ethereum: JsValue,
+    wallet_methods: Vec<String>,
 }
 
 impl WalletLayer {
-    /// Create new wallet layer
+    /// Create new wallet layer routing [`DEFAULT_WALLET_METHODS`] to the wallet
     pub fn new(ethereum: JsValue) -> Self {
-        Self { ethereum }
+        Self {
+            ethereum,
+            wallet_methods: DEFAULT_WALLET_METHODS.iter().map(|method| method.to_string()).collect(),
+        }
     }
 
     /// Create from window.ethereum
@@ -29,6 +63,24 @@ impl WalletLayer {
         let ethereum = Eip1193Transport::get_ethereum()?;
         Ok(Self::new(ethereum))
     }
+
+    /// Also route `method` to the wallet
+    ///
+    /// A trailing `*` matches every method with that prefix, e.g. `"snap_*"`.
+    pub fn with_wallet_method(mut self, method: impl Into<String>) -> Self {
+        self.wallet_methods.push(method.into());
+        self
+    }
+
+    /// Route exactly `methods` to the wallet, replacing [`DEFAULT_WALLET_METHODS`]
+    pub fn with_wallet_methods<I, S>(mut self, methods: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.wallet_methods = methods.into_iter().map(Into::into).collect();
+        self
+    }
 }
 
 impl<P, N> ProviderLayer<P, N> for WalletLayer
@@ -39,25 +91,40 @@ where
     type Provider = WalletProvider<P, N>;
 
     fn layer(&self, inner: P) -> Self::Provider {
+        let wallet_transport = Eip1193Transport::new(self.ethereum.clone());
+
+        // Same client settings as the inner provider, with wallet methods split off
+        let rpc_client = inner.root().client();
+        let router = WalletRouter {
+            wallet: wallet_transport.clone(),
+            rpc: rpc_client.transport().clone(),
+            wallet_methods: self.wallet_methods.clone().into(),
+        };
+        let client = RpcClient::new(router, rpc_client.is_local())
+            .with_poll_interval(rpc_client.poll_interval());
+
         WalletProvider {
             inner,
-            wallet_transport: Eip1193Transport::new(self.ethereum.clone()),
+            root: RootProvider::new(client),
+            wallet_transport,
             _phantom: PhantomData,
         }
     }
 }
 
 /// Provider with EIP-1193 wallet routing
-pub struct WalletProvider<P, N> {
+pub struct WalletProvider<P, N: Network> {
     inner: P,
+    root: RootProvider<N>,
     wallet_transport: Eip1193Transport,
     _phantom: PhantomData<N>,
 }
 
-impl<P: Clone, N> Clone for WalletProvider<P, N> {
+impl<P: Clone, N: Network> Clone for WalletProvider<P, N> {
     fn clone(&self) -> Self {
         Self {
             inner: self.inner.clone(),
+            root: self.root.clone(),
             wallet_transport: self.wallet_transport.clone(),
             _phantom: PhantomData,
         }
@@ -67,6 +134,7 @@ impl<P: Clone, N> Clone for WalletProvider<P, N> {
 impl<P, N> std::fmt::Debug for WalletProvider<P, N>
 where
     P: std::fmt::Debug,
+    N: Network,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("WalletProvider")
@@ -82,8 +150,11 @@ where
     P: Provider<N>,
     N: Network,
 {
+    /// Root provider whose client sends wallet methods to the wallet
+    ///
+    /// It shares the inner provider's transport for everything else.
     fn root(&self) -> &RootProvider<N> {
-        self.inner.root()
+        &self.root
     }
 
     /// Override send_transaction to route through EIP-1193 wallet
@@ -121,7 +192,7 @@ where
     }
 }
 
-impl<P, N> WalletProvider<P, N> {
+impl<P, N: Network> WalletProvider<P, N> {
     /// Get reference to wallet transport
     pub fn wallet_transport(&self) -> &Eip1193Transport {
         &self.wallet_transport
@@ -153,5 +224,149 @@ pub fn with_wallet(ethereum: JsValue, rpc_url: url::Url) -> Result<impl Provider
 }
 
 // WASM is single-threaded
-unsafe impl<P, N> Send for WalletProvider<P, N> {}
-unsafe impl<P, N> Sync for WalletProvider<P, N> {}
+unsafe impl<P, N: Network> Send for WalletProvider<P, N> {}
+unsafe impl<P, N: Network> Sync for WalletProvider<P, N> {}
+
+/// Transport sending wallet methods to the wallet and everything else to the RPC
+#[derive(Clone, Debug)]
+struct WalletRouter {
+    wallet: Eip1193Transport,
+    rpc: BoxTransport,
+    wallet_methods: Arc<[String]>,
+}
+
+impl WalletRouter {
+    fn is_wallet_method(&self, method: &str) -> bool {
+        is_wallet_method(&self.wallet_methods, method)
+    }
+
+    /// Split a batch between the wallet and the RPC
+    ///
+    /// The wallet handles one request at a time, so its share is sent individually.
+    /// A failed wallet request gets an error response, like it would from a node,
+    /// so the other requests in the batch still get their results.
+    fn call_batch(&mut self, requests: Vec<SerializedRequest>) -> TransportFut<'static> {
+        let (wallet_requests, rpc_requests): (Vec<_>, Vec<_>) = requests
+            .into_iter()
+            .partition(|request| self.is_wallet_method(request.method()));
+
+        if wallet_requests.is_empty() {
+            return self.rpc.call(RequestPacket::Batch(rpc_requests));
+        }
+
+        let wallet_calls: Vec<_> = wallet_requests
+            .into_iter()
+            .map(|request| {
+                let id = request.id().clone();
+                let call = self.wallet.call(RequestPacket::Single(request));
+                async move {
+                    call.await.map_or_else(|e| vec![error_response(id, e)], into_responses)
+                }
+            })
+            .collect();
+        let rpc_call = (!rpc_requests.is_empty())
+            .then(|| self.rpc.call(RequestPacket::Batch(rpc_requests)));
+
+        Box::pin(async move {
+            let rpc_responses = async move {
+                match rpc_call {
+                    Some(call) => call.await.map(into_responses),
+                    None => Ok(Vec::new()),
+                }
+            };
+            let (wallet_responses, rpc_responses) = join(join_all(wallet_calls), rpc_responses).await;
+
+            // Batch responses are matched by ID, so order doesn't matter
+            let mut responses = rpc_responses?;
+            responses.extend(wallet_responses.into_iter().flatten());
+            Ok(ResponsePacket::Batch(responses))
+        })
+    }
+}
+
+impl Service<RequestPacket> for WalletRouter {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // The wallet transport is always ready
+        self.rpc.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: RequestPacket) -> Self::Future {
+        match req {
+            RequestPacket::Single(request) if self.is_wallet_method(request.method()) => {
+                self.wallet.call(RequestPacket::Single(request))
+            }
+            RequestPacket::Single(request) => self.rpc.call(RequestPacket::Single(request)),
+            RequestPacket::Batch(requests) => self.call_batch(requests),
+        }
+    }
+}
+
+/// Check if `method` matches one of `wallet_methods`, where a trailing `*` matches any suffix
+fn is_wallet_method(wallet_methods: &[String], method: &str) -> bool {
+    wallet_methods.iter().any(|pattern| match pattern.strip_suffix('*') {
+        Some(prefix) => method.starts_with(prefix),
+        None => pattern == method,
+    })
+}
+
+/// JSON-RPC error response for a wallet request that failed
+///
+/// Keeps the EIP-1193 code, so the error can still be told apart, e.g. as a user rejection.
+fn error_response(id: Id, error: TransportError) -> Response {
+    let payload = match error {
+        RpcError::ErrorResp(payload) => payload,
+        error => match Eip1193Error::from_transport_error(&error) {
+            Some(e) if e.code() != 0 => ErrorPayload {
+                code: e.code().into(),
+                message: e.to_string().into(),
+                data: None,
+            },
+            _ => ErrorPayload::internal_error_message(error.to_string().into()),
+        },
+    };
+
+    Response {
+        id,
+        payload: ResponsePayload::Failure(payload),
+    }
+}
+
+fn into_responses(packet: ResponsePacket) -> Vec<Response> {
+    match packet {
+        ResponsePacket::Single(response) => vec![response],
+        ResponsePacket::Batch(responses) => responses,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_wallet_method() {
+        let methods: Vec<String> = DEFAULT_WALLET_METHODS.iter().map(|method| method.to_string()).collect();
+
+        assert!(is_wallet_method(&methods, "personal_sign"));
+        assert!(is_wallet_method(&methods, "eth_signTypedData_v4"));
+        assert!(is_wallet_method(&methods, "wallet_switchEthereumChain"));
+        assert!(!is_wallet_method(&methods, "eth_call"));
+        assert!(!is_wallet_method(&methods, "eth_signTypedData_v5"));
+        assert!(!is_wallet_method(&methods, "wallet"));
+    }
+
+    #[test]
+    fn test_error_response_keeps_code_and_id() {
+        let response = error_response(Id::Number(7), Eip1193Error::UserRejectedRequest.into_transport_error());
+
+        assert_eq!(response.id, Id::Number(7));
+        let ResponsePayload::Failure(payload) = response.payload else {
+            panic!("expected an error response");
+        };
+        assert_eq!(payload.code, 4001);
+        assert!(Eip1193Error::from_error_payload(&payload).is_user_rejection());
+    }
+}
@@ -1,8 +1,11 @@
 use alloy::hex;
 use alloy::network::{TxSigner, NetworkWallet, Ethereum};
-use alloy::primitives::{Address, Signature, B256, ChainId};
-use alloy::signers::Signer;
-use alloy::consensus::SignableTransaction;
+use alloy::primitives::{Address, Bytes, Signature, B256, ChainId};
+use alloy::signers::{Signer, UnsupportedSignerOperation};
+use alloy::consensus::{SignableTransaction, TxEnvelope};
+use alloy::eips::eip2718::Decodable2718;
+use alloy::rpc::types::{TransactionInput, TransactionRequest};
+use serde::Deserialize;
 use alloy::dyn_abi::eip712::TypedData;
 use alloy::providers::RootProvider;
 use async_trait::async_trait;
@@ -17,6 +20,10 @@ use crate::error::Eip1193Error;
 /// This signer wraps `window.ethereum` to provide signing capabilities
 /// without acting as a full RPC provider. It should be combined with an
 /// HTTP transport provider for blockchain RPC operations.
+///
+/// Transactions are signed with `eth_signTransaction`, which wallets such as
+/// Frame and Rabby support. Falling back to `eth_sign` for wallets that don't
+/// is opt-in, see [`with_eth_sign_fallback`](Self::with_eth_sign_fallback).
 #[derive(Clone, Debug)]
 pub struct Eip1193Signer {
     /// Transport for making EIP-1193 RPC calls
@@ -25,6 +32,8 @@ pub struct Eip1193Signer {
     address: Address,
     /// Chain ID for EIP-155 transaction signing
     chain_id: Option<ChainId>,
+    /// Whether raw hashes may be signed with `eth_sign`
+    allow_eth_sign: bool,
 }
 
 // WASM is single-threaded, so Send/Sync are safe
@@ -42,6 +51,7 @@ impl Eip1193Signer {
             transport: Eip1193Transport::new(ethereum),
             address,
             chain_id: None,
+            allow_eth_sign: false,
         }
     }
 
@@ -56,6 +66,25 @@ impl Eip1193Signer {
             transport: Eip1193Transport::new(ethereum),
             address,
             chain_id: Some(chain_id),
+            allow_eth_sign: false,
+        }
+    }
+
+    /// Create a signer that sends its requests through an existing transport.
+    ///
+    /// Use this to sign with the wallet an app is already connected to, rather
+    /// than whichever provider is injected as `window.ethereum`.
+    ///
+    /// # Arguments
+    /// * `transport` - Transport of the connected wallet
+    /// * `address` - The currently connected account address
+    /// * `chain_id` - The chain ID for EIP-155 signing, if known
+    pub fn from_transport(transport: Eip1193Transport, address: Address, chain_id: Option<ChainId>) -> Self {
+        Self {
+            transport,
+            address,
+            chain_id,
+            allow_eth_sign: false,
         }
     }
 
@@ -89,6 +118,17 @@ impl Eip1193Signer {
         Ok(Self::new_with_chain_id(ethereum, address, chain_id))
     }
 
+    /// Allow signing raw hashes with `eth_sign`
+    ///
+    /// Off by default: `eth_sign` lets a site have the user blind-sign anything,
+    /// including transactions, so most wallets refuse it or warn the user. When
+    /// enabled, `sign_hash` uses it, and `sign_transaction` falls back to it for
+    /// wallets without `eth_signTransaction`.
+    pub fn with_eth_sign_fallback(mut self, allow: bool) -> Self {
+        self.allow_eth_sign = allow;
+        self
+    }
+
     /// Get the ethereum provider object
     pub fn ethereum(&self) -> &JsValue {
         self.transport.ethereum()
@@ -132,6 +172,10 @@ impl Eip1193Signer {
 impl Signer<Signature> for Eip1193Signer {
     #[inline]
     async fn sign_hash(&self, hash: &B256) -> Result<Signature, alloy::signers::Error> {
+        if !self.allow_eth_sign {
+            return Err(alloy::signers::Error::UnsupportedOperation(UnsupportedSignerOperation::SignHash));
+        }
+
         // eth_sign params: [address, message_hash]
         // Following Alloy's pattern of using tuples for RPC params
         let params = (
@@ -161,7 +205,9 @@ impl Signer<Signature> for Eip1193Signer {
         let sig_str: String = self.transport
             .request("personal_sign", params)
             .await
-            .map_err(|e| alloy::signers::Error::other(format!("Sign message failed: {:?}", e)))?;
+            // Keep the Eip1193Error as the source so callers can downcast it,
+            // e.g. to tell a user rejection apart
+            .map_err(alloy::signers::Error::other)?;
 
         sig_str
             .parse()
@@ -200,7 +246,7 @@ impl Signer<Signature> for Eip1193Signer {
         let sig_str: String = self.transport
             .request("eth_signTypedData_v4", params)
             .await
-            .map_err(|e| alloy::signers::Error::other(format!("Sign typed data failed: {:?}", e)))?;
+            .map_err(alloy::signers::Error::other)?;
 
         sig_str
             .parse()
@@ -220,24 +266,90 @@ impl TxSigner<Signature> for Eip1193Signer {
         &self,
         tx: &mut dyn SignableTransaction<Signature>,
     ) -> Result<Signature, alloy::signers::Error> {
-        // CAVEAT: This uses eth_sign which shows warnings in MetaMask and most wallets
-        // For production use, prefer Eip1193Provider which uses eth_sendTransaction
-        // This fallback implementation is provided for API compatibility in edge cases
-
-        log::warn!(
-            "Using eth_sign for transaction signing. \
-             MetaMask and other wallets will show security warnings. \
-             For better UX, use Eip1193Provider with send_transaction override."
-        );
+        let request = transaction_request(tx, self.address);
+
+        let response: SignTransactionResponse = match self.transport
+            .request("eth_signTransaction", (request,))
+            .await
+        {
+            Ok(response) => response,
+            Err(e) if e.is_unsupported_method() && self.allow_eth_sign => {
+                log::warn!(
+                    "Wallet doesn't support eth_signTransaction, falling back to eth_sign. \
+                     Most wallets will show a security warning."
+                );
+                return self.sign_hash(&tx.signature_hash()).await;
+            }
+            Err(e) => return Err(alloy::signers::Error::other(e)),
+        };
+
+        signature_from_raw(response.raw(), tx)
+    }
+}
 
-        // Encode the transaction for signing
-        let mut tx_encoded = Vec::new();
-        tx.encode_for_signing(&mut tx_encoded);
+/// Extract the signature from a raw transaction signed by the wallet
+///
+/// The signature is only valid for `tx` if the wallet didn't change it, e.g. by
+/// adjusting the gas price, so any other transaction is rejected.
+fn signature_from_raw(raw: &Bytes, tx: &dyn SignableTransaction<Signature>) -> Result<Signature, alloy::signers::Error> {
+    let envelope = TxEnvelope::decode_2718(&mut raw.as_ref())
+        .map_err(|e| alloy::signers::Error::other(format!("Failed to decode signed transaction: {}", e)))?;
+
+    if envelope.signature_hash() != tx.signature_hash() {
+        return Err(alloy::signers::Error::message("Wallet signed a different transaction than requested"));
+    }
 
-        // Sign the transaction hash using eth_sign (will show scary warning)
-        let tx_hash = alloy::primitives::keccak256(&tx_encoded);
+    Ok(*envelope.signature())
+}
 
-        self.sign_hash(&tx_hash).await
+/// Response of `eth_signTransaction`
+///
+/// Geth-style nodes return the raw transaction together with its JSON form,
+/// while most wallets return only the raw transaction.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SignTransactionResponse {
+    Raw(Bytes),
+    WithTx { raw: Bytes },
+}
+
+impl SignTransactionResponse {
+    fn raw(&self) -> &Bytes {
+        match self {
+            Self::Raw(raw) | Self::WithTx { raw } => raw,
+        }
+    }
+}
+
+/// Build the `eth_signTransaction` request for a transaction
+///
+/// Mirrors `TransactionRequest::from_transaction`, which needs an owned transaction.
+/// The calldata is sent as both `input` and `data` since wallets differ in which
+/// one they read.
+fn transaction_request(tx: &dyn SignableTransaction<Signature>, from: Address) -> TransactionRequest {
+    let (gas_price, max_fee_per_gas) = if tx.is_dynamic_fee() {
+        (None, Some(tx.max_fee_per_gas()))
+    } else {
+        (tx.gas_price(), None)
+    };
+
+    TransactionRequest {
+        from: Some(from),
+        to: Some(tx.kind()),
+        gas_price,
+        max_fee_per_gas,
+        max_priority_fee_per_gas: tx.max_priority_fee_per_gas(),
+        gas: Some(tx.gas_limit()),
+        value: Some(tx.value()),
+        input: TransactionInput::both(tx.input().clone()),
+        nonce: Some(tx.nonce()),
+        chain_id: tx.chain_id(),
+        access_list: tx.access_list().cloned(),
+        max_fee_per_blob_gas: tx.max_fee_per_blob_gas(),
+        blob_versioned_hashes: tx.blob_versioned_hashes().map(Vec::from),
+        transaction_type: Some(tx.ty()),
+        sidecar: None,
+        authorization_list: tx.authorization_list().map(|list| list.to_vec()),
     }
 }
 
@@ -281,3 +393,129 @@ impl NetworkWallet<Ethereum> for Eip1193Signer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::consensus::{TxEip1559, TxLegacy};
+    use alloy::eips::eip2718::Encodable2718;
+    use alloy::network::TxSignerSync;
+    use alloy::primitives::{TxKind, U256};
+    use alloy::signers::local::PrivateKeySigner;
+
+    fn eip1559_transaction() -> TxEip1559 {
+        TxEip1559 {
+            chain_id: 1,
+            nonce: 0,
+            gas_limit: 50_000,
+            max_fee_per_gas: 30_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+            to: TxKind::Call(Address::repeat_byte(0x22)),
+            value: U256::ZERO,
+            access_list: Default::default(),
+            input: Bytes::from_static(&[0xa9, 0x05, 0x9c, 0xbb]),
+        }
+    }
+
+    /// Sign `tx` the way a wallet answering `eth_signTransaction` would
+    fn sign_raw<T>(signer: &PrivateKeySigner, mut tx: T) -> (Bytes, Signature)
+    where
+        T: SignableTransaction<Signature>,
+        TxEnvelope: From<alloy::consensus::Signed<T>>,
+    {
+        let signature = signer.sign_transaction_sync(&mut tx).unwrap();
+        let envelope = TxEnvelope::from(tx.into_signed(signature));
+        (envelope.encoded_2718().into(), signature)
+    }
+
+    #[test]
+    fn test_signature_from_raw() {
+        let signer = PrivateKeySigner::random();
+        let tx = eip1559_transaction();
+        let (raw, signature) = sign_raw(&signer, tx.clone());
+
+        assert_eq!(signature_from_raw(&raw, &tx).unwrap(), signature);
+    }
+
+    #[test]
+    fn test_signature_from_raw_rejects_changed_transaction() {
+        let signer = PrivateKeySigner::random();
+        let tx = eip1559_transaction();
+        let (raw, _) = sign_raw(&signer, TxEip1559 { gas_limit: 60_000, ..tx.clone() });
+
+        assert!(signature_from_raw(&raw, &tx).is_err());
+    }
+
+    #[test]
+    fn test_signature_from_raw_legacy() {
+        let signer = PrivateKeySigner::random();
+        let tx = TxLegacy {
+            chain_id: Some(1),
+            nonce: 3,
+            gas_price: 20_000_000_000,
+            gas_limit: 21_000,
+            to: TxKind::Call(Address::repeat_byte(0x22)),
+            value: U256::from(1),
+            input: Bytes::new(),
+        };
+        let (raw, signature) = sign_raw(&signer, tx.clone());
+
+        let recovered = signature_from_raw(&raw, &tx).unwrap();
+        assert_eq!(recovered, signature);
+        assert_eq!(recovered.recover_address_from_prehash(&tx.signature_hash()).unwrap(), signer.address());
+    }
+
+    #[test]
+    fn test_parse_sign_transaction_response() {
+        let raw = Bytes::from_static(&[0x02, 0xde, 0xad, 0xbe, 0xef]);
+
+        let bare: SignTransactionResponse = serde_json::from_value(serde_json::json!("0x02deadbeef")).unwrap();
+        assert_eq!(bare.raw(), &raw);
+
+        let with_tx: SignTransactionResponse = serde_json::from_value(serde_json::json!({
+            "raw": "0x02deadbeef",
+            "tx": { "nonce": "0x1", "hash": format!("{:?}", B256::ZERO) }
+        }))
+        .unwrap();
+        assert_eq!(with_tx.raw(), &raw);
+    }
+
+    #[test]
+    fn test_legacy_transaction_request() {
+        let from = Address::repeat_byte(0x11);
+        let tx = TxLegacy {
+            chain_id: Some(1),
+            nonce: 3,
+            gas_price: 20_000_000_000,
+            gas_limit: 21_000,
+            to: TxKind::Call(Address::repeat_byte(0x22)),
+            value: U256::from(1),
+            input: Bytes::new(),
+        };
+
+        let request = transaction_request(&tx, from);
+
+        assert_eq!(request.from, Some(from));
+        assert_eq!(request.gas_price, Some(20_000_000_000));
+        assert_eq!(request.max_fee_per_gas, None);
+        assert_eq!(request.max_priority_fee_per_gas, None);
+        assert_eq!(request.nonce, Some(3));
+        assert_eq!(request.chain_id, Some(1));
+        assert_eq!(request.transaction_type, Some(0));
+    }
+
+    #[test]
+    fn test_eip1559_transaction_request() {
+        let tx = eip1559_transaction();
+        let calldata = tx.input.clone();
+
+        let request = transaction_request(&tx, Address::repeat_byte(0x11));
+
+        assert_eq!(request.gas_price, None);
+        assert_eq!(request.max_fee_per_gas, Some(30_000_000_000));
+        assert_eq!(request.max_priority_fee_per_gas, Some(1_000_000_000));
+        assert_eq!(request.input.input, Some(calldata.clone()));
+        assert_eq!(request.input.data, Some(calldata));
+        assert_eq!(request.transaction_type, Some(2));
+    }
+}
@@ -0,0 +1,89 @@
+//! Removable EIP-1193 event listeners
+//!
+//! EIP-1193 providers expose a Node.js style `on` / `removeListener` API. This module
+//! wraps a listener registration in a handle, so a listener lives exactly as long as the
+//! Rust value that owns it instead of being leaked with `Closure::forget`.
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::js_sys;
+use crate::error::Eip1193Error;
+
+/// Handle to an EIP-1193 event listener
+///
+/// Created with [`Eip1193Transport::on`](crate::Eip1193Transport::on). Dropping the handle
+/// removes the listener from the provider with `removeListener`.
+#[must_use = "the listener is removed as soon as the subscription is dropped"]
+pub struct Subscription {
+    ethereum: JsValue,
+    event: String,
+    closure: Option<Closure<dyn FnMut(JsValue)>>,
+}
+
+impl std::fmt::Debug for Subscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Subscription").field("event", &self.event).finish()
+    }
+}
+
+impl Subscription {
+    /// Register `handler` for `event` on the provider
+    pub(crate) fn new<F>(ethereum: JsValue, event: &str, handler: F) -> Result<Self, Eip1193Error>
+    where
+        F: FnMut(JsValue) + 'static,
+    {
+        let closure = Closure::wrap(Box::new(handler) as Box<dyn FnMut(JsValue)>);
+        call_listener_method(&ethereum, "on", event, &closure)?;
+
+        Ok(Self {
+            ethereum,
+            event: event.to_string(),
+            closure: Some(closure),
+        })
+    }
+
+    /// Name of the event this subscription listens to
+    pub fn event(&self) -> &str {
+        &self.event
+    }
+
+    /// Keep the listener registered for the lifetime of the page
+    pub fn forget(mut self) {
+        if let Some(closure) = self.closure.take() {
+            closure.forget();
+        }
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        let Some(closure) = self.closure.take() else {
+            return;
+        };
+
+        if let Err(e) = call_listener_method(&self.ethereum, "removeListener", &self.event, &closure) {
+            log::warn!("Failed to remove {} listener: {}", self.event, e);
+            // The provider may still call the handler, so it must stay alive
+            closure.forget();
+        }
+    }
+}
+
+/// Call `provider[method](event, closure)`
+fn call_listener_method(
+    ethereum: &JsValue,
+    method: &str,
+    event: &str,
+    closure: &Closure<dyn FnMut(JsValue)>,
+) -> Result<(), Eip1193Error> {
+    let function = js_sys::Reflect::get(ethereum, &method.into())
+        .map_err(Eip1193Error::from_js_value)?
+        .dyn_into::<js_sys::Function>()
+        .map_err(|_| Eip1193Error::JsError(format!("Provider has no {} method", method)))?;
+
+    function
+        .call2(ethereum, &event.into(), closure.as_ref().unchecked_ref())
+        .map_err(Eip1193Error::from_js_value)?;
+
+    Ok(())
+}
@@ -19,6 +19,7 @@ use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::JsFuture;
 use web_sys::js_sys;
 use crate::error::Eip1193Error;
+use crate::subscription::Subscription;
 
 /// EIP-1193 Transport implementation for Alloy
 ///
@@ -71,6 +72,28 @@ impl Eip1193Transport {
         &self.ethereum
     }
 
+    /// Listen for an EIP-1193 provider event
+    ///
+    /// `event` is one of the events defined by EIP-1193 (`accountsChanged`, `chainChanged`,
+    /// `connect`, `disconnect`, `message`). The handler receives the raw event payload and
+    /// stays registered until the returned [`Subscription`] is dropped.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let subscription = transport.on("chainChanged", |chain_id| {
+    ///     log::info!("Chain changed to {:?}", chain_id);
+    /// })?;
+    ///
+    /// // Removes the listener
+    /// drop(subscription);
+    /// ```
+    pub fn on<F>(&self, event: &str, handler: F) -> Result<Subscription, Eip1193Error>
+    where
+        F: FnMut(JsValue) + 'static,
+    {
+        Subscription::new(self.ethereum.clone(), event, handler)
+    }
+
     /// Create an `RpcClient` from this transport
     ///
     /// This is the modern Alloy pattern for creating providers. The `RpcClient` can then be used
@@ -0,0 +1,99 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use alloy::network::Ethereum;
+use alloy::primitives::Address;
+use alloy::providers::RootProvider;
+use crate::error::RequestError;
+use crate::hooks::use_balance::IERC20;
+use crate::hooks::use_wallet;
+use crate::i18n::use_i18n;
+use crate::provider::{Eip1193, Eip1193Transport, WatchAssetParams};
+use crate::state::connection::{use_connection_state, WalletProvider};
+use crate::state::toast::{use_toast_state, Toast, ToastKind};
+
+/// Button that asks the connected wallet to track an ERC-20 token (EIP-747)
+///
+/// The token's symbol and decimals are read on-chain when clicked, so only the
+/// address is needed. Disabled while disconnected.
+#[component]
+pub fn AddTokenButton(
+    /// Token contract address
+    token: Address,
+    /// Image the wallet shows for the token
+    #[prop(optional, into)]
+    image: Option<String>,
+) -> impl IntoView {
+    let state = use_connection_state();
+    let wallet = use_wallet();
+    let toast_state = use_toast_state();
+    let i18n = use_i18n();
+
+    let adding = RwSignal::new(false);
+    let image = StoredValue::new(image);
+
+    let handle_click = move |_| {
+        let (Some(provider), Some(transport)) = (state.provider.get_untracked(), state.wallet_transport.get_untracked()) else {
+            return;
+        };
+        if adding.get_untracked() {
+            return;
+        }
+
+        adding.set(true);
+        spawn_local(async move {
+            match watch_token(provider, transport, token, image.get_value()).await {
+                Ok((symbol, true)) => {
+                    toast_state.success(i18n.t("add_token.added").replace("{symbol}", &symbol));
+                }
+                // Declined in the wallet
+                Ok((_, false)) => {}
+                Err(e) if e.is_user_rejection() => {}
+                Err(e) => {
+                    log::error!("Failed to add token {}: {}", token, e);
+                    toast_state.push(
+                        Toast::new(ToastKind::Error, i18n.t("add_token.failed"))
+                            .with_description(i18n.t(e.i18n_key()))
+                    );
+                }
+            }
+            adding.set(false);
+        });
+    };
+
+    view! {
+        <button
+            class="nk-button nk-button-primary"
+            on:click=handle_click
+            data-nk=""
+            disabled=move || !wallet.is_connected.get() || adding.get()
+            style=move || if adding.get() { "opacity: 0.6; cursor: wait;" } else { "" }
+        >
+            {move || if adding.get() { i18n.t("add_token.adding") } else { i18n.t("add_token.label") }}
+        </button>
+    }
+}
+
+/// Read the token's symbol and decimals, then ask the wallet to track it
+///
+/// Returns the symbol and whether the user added the token.
+async fn watch_token(
+    provider: WalletProvider,
+    transport: Eip1193Transport,
+    token: Address,
+    image: Option<String>,
+) -> Result<(String, bool), RequestError> {
+    // Token reads only need the HTTP transport underneath the wallet layer
+    let erc20 = IERC20::new(token, provider.root());
+    let symbol = erc20.symbol().call().await?;
+    let decimals = erc20.decimals().call().await?;
+
+    let mut asset = WatchAssetParams::erc20(token, symbol.clone(), decimals);
+    if let Some(image) = image {
+        asset = asset.with_image(image);
+    }
+
+    let wallet = RootProvider::<Ethereum>::new(transport.into_client());
+    let added = wallet.watch_asset(asset).await?;
+
+    Ok((symbol, added))
+}
@@ -1,15 +1,29 @@
 use leptos::prelude::*;
 use crate::state::modal::use_modal_state;
-use crate::hooks::use_wallet;
+use crate::state::transaction::use_transaction_store;
+use crate::hooks::{use_wallet, use_ens_name, use_ens_avatar};
+use crate::i18n::use_i18n;
 use crate::utils::format::format_address;
 
 #[component]
 pub fn ConnectButton() -> impl IntoView {
     let modal_state = use_modal_state();
     let wallet = use_wallet();
+    let i18n = use_i18n();
+    let ens_name = use_ens_name(wallet.address);
+    let ens_avatar = use_ens_avatar(wallet.address);
+    let transaction_store = use_transaction_store();
+
+    let has_pending = move || match (wallet.address.get(), wallet.chain_id.get()) {
+        (Some(address), Some(chain_id)) => transaction_store.pending_count(address, chain_id) > 0,
+        _ => false,
+    };
 
     let handle_click = move |_| {
-        if wallet.is_connected.get() {
+        if wallet.is_wrong_network.get() {
+            log::debug!("Opening chain modal");
+            modal_state.open_chain();
+        } else if wallet.is_connected.get() {
             log::debug!("Opening account modal");
             modal_state.open_account();
         } else {
@@ -18,32 +32,61 @@ pub fn ConnectButton() -> impl IntoView {
         }
     };
 
+    // Restoring a session looks the same as connecting, so the button
+    // doesn't flash "Connect Wallet" on page load
+    let is_busy = move || wallet.is_connecting.get() || wallet.is_reconnecting.get();
+
     // Dynamic button text based on connection state
     let button_text = move || {
-        if wallet.is_connecting.get() {
-            "Connecting...".to_string()
+        if is_busy() {
+            i18n.t("connect_wallet.connecting")
+        } else if wallet.is_wrong_network.get() {
+            i18n.t("connect_wallet.wrong_network")
+        } else if let Some(name) = ens_name.get() {
+            name
         } else if let Some(addr) = wallet.address.get() {
             format_address(&addr)
         } else {
-            "Connect Wallet".to_string()
+            i18n.t("connect_wallet.label")
         }
     };
 
     view! {
         <button
-            class="nk-button nk-button-primary"
+            class=move || {
+                if wallet.is_wrong_network.get() {
+                    "nk-button nk-button-primary nk-button-error"
+                } else {
+                    "nk-button nk-button-primary"
+                }
+            }
             on:click=handle_click
             data-nk=""
-            disabled=move || wallet.is_connecting.get()
+            disabled=is_busy
             style=move || {
-                if wallet.is_connecting.get() {
+                if is_busy() {
                     "opacity: 0.6; cursor: wait;"
                 } else {
                     ""
                 }
             }
         >
+            <Show when=move || wallet.is_connected.get() && !wallet.is_wrong_network.get() && !is_busy()>
+                {move || ens_avatar.get().map(|src| view! {
+                    <img
+                        src=src
+                        alt=""
+                        style="width: 20px; height: 20px; border-radius: 50%; margin-right: 8px; vertical-align: middle;"
+                    />
+                })}
+            </Show>
             {button_text}
+            <Show when=move || wallet.is_connected.get() && has_pending()>
+                <span
+                    class="nk-spinner"
+                    style="width: 14px; height: 14px; margin-left: 8px; vertical-align: middle;"
+                ></span>
+            </Show>
         </button>
     }
 }
@@ -1,8 +1,12 @@
 pub mod connect_button;
+pub mod add_token_button;
 pub mod provider;
 pub mod primitives;
 pub mod modals;
+pub mod toaster;
 
 pub use connect_button::ConnectButton;
+pub use add_token_button::AddTokenButton;
 pub use provider::{NexumKitProvider, NexumKitProviderSimple};
-pub use modals::{ConnectModal, AccountModal};
+pub use modals::{ConnectModal, AccountModal, ChainModal};
+pub use toaster::Toaster;
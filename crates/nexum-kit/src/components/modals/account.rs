@@ -3,8 +3,10 @@ use leptos::callback::{UnsyncCallback, Callback};
 use crate::components::primitives::{Dialog, Text, BoxFontWeight};
 use crate::state::modal::{use_modal_state, ModalType};
 use crate::state::connection::use_connection_state;
-use crate::hooks::use_wallet;
-use crate::utils::format::format_address;
+use crate::state::transaction::{use_transaction_store, Transaction, TransactionStatus};
+use crate::hooks::{use_wallet, use_ens_name, use_ens_avatar};
+use crate::i18n::use_i18n;
+use crate::utils::format::{explorer_tx_url, format_address};
 use wasm_bindgen_futures::spawn_local;
 
 #[component]
@@ -12,6 +14,26 @@ pub fn AccountModal() -> impl IntoView {
     let modal_state = use_modal_state();
     let connection_state = use_connection_state();
     let wallet = use_wallet();
+    let ens_name = use_ens_name(wallet.address);
+    let ens_avatar = use_ens_avatar(wallet.address);
+    let transaction_store = use_transaction_store();
+    let i18n = use_i18n();
+
+    // Connected account's transactions on the current chain, newest first
+    let transactions = Signal::derive(move || {
+        let (Some(address), Some(chain_id)) = (wallet.address.get(), wallet.chain_id.get()) else {
+            return Vec::new();
+        };
+        let mut txs = transaction_store.get_transactions(address, chain_id);
+        txs.reverse();
+        txs
+    });
+
+    let handle_clear = move |_| {
+        if let (Some(address), Some(chain_id)) = (wallet.address.get_untracked(), wallet.chain_id.get_untracked()) {
+            transaction_store.clear_transactions(address, chain_id);
+        }
+    };
 
     let is_open = modal_state.is_open(ModalType::Account);
     let on_close = UnsyncCallback::new(move |_| modal_state.close());
@@ -43,6 +65,35 @@ pub fn AccountModal() -> impl IntoView {
                     "Account"
                 </Text>
 
+                // ENS profile
+                <Show when=move || ens_name.get().is_some() || ens_avatar.get().is_some()>
+                    <div style="
+                        display: flex;
+                        flex-direction: column;
+                        align-items: center;
+                        gap: 8px;
+                        margin-bottom: 16px;
+                    ">
+                        {move || ens_avatar.get().map(|src| view! {
+                            <img
+                                src=src
+                                alt=""
+                                style="width: 74px; height: 74px; border-radius: 50%; object-fit: cover;"
+                            />
+                        })}
+                        {move || ens_name.get().map(|name| view! {
+                            <Text
+                                as_element="p"
+                                size="18px"
+                                font_weight=BoxFontWeight::Bold
+                                color="modalText"
+                            >
+                                {name}
+                            </Text>
+                        })}
+                    </div>
+                </Show>
+
                 // Address display
                 <div style="
                     padding: 16px;
@@ -69,6 +120,98 @@ pub fn AccountModal() -> impl IntoView {
                     </Text>
                 </div>
 
+                // Recent transactions
+                <div style="margin-bottom: 16px;">
+                    <div style="display: flex; align-items: center; justify-content: space-between; margin-bottom: 8px;">
+                        <Text
+                            as_element="h3"
+                            size="14px"
+                            font_weight=BoxFontWeight::Semibold
+                            color="modalTextSecondary"
+                        >
+                            {move || i18n.t("account_modal.recent_transactions")}
+                        </Text>
+                        <Show when=move || !transactions.with(Vec::is_empty)>
+                            <button
+                                style="
+                                    background: none;
+                                    border: none;
+                                    padding: 0;
+                                    color: var(--nk-colors-accentColor);
+                                    font-family: var(--nk-fonts-body);
+                                    font-size: 14px;
+                                    font-weight: 600;
+                                    cursor: pointer;
+                                "
+                                on:click=handle_clear
+                            >
+                                {move || i18n.t("account_modal.clear_all")}
+                            </button>
+                        </Show>
+                    </div>
+
+                    <Show
+                        when=move || !transactions.with(Vec::is_empty)
+                        fallback=move || view! {
+                            <Text
+                                as_element="p"
+                                size="14px"
+                                color="modalTextDim"
+                            >
+                                {move || i18n.t("account_modal.no_transactions")}
+                            </Text>
+                        }
+                    >
+                        <div style="display: flex; flex-direction: column; gap: 4px; max-height: 240px; overflow-y: auto;">
+                            {move || transactions.get().into_iter().map(|tx| {
+                                let status_label = i18n.t(tx.status.i18n_key());
+                                let label = tx.description.clone().unwrap_or_else(|| status_label.clone());
+                                let url = explorer_tx_url(tx.chain_id, &tx.hash);
+
+                                view! {
+                                    <div style="
+                                        display: flex;
+                                        align-items: center;
+                                        gap: 12px;
+                                        padding: 8px 0;
+                                    ">
+                                        {status_icon(&tx)}
+                                        <div style="flex: 1; min-width: 0;">
+                                            <Text
+                                                as_element="p"
+                                                size="14px"
+                                                font_weight=BoxFontWeight::Semibold
+                                                color="modalText"
+                                                additional_style="overflow: hidden; text-overflow: ellipsis; white-space: nowrap;"
+                                            >
+                                                {label}
+                                            </Text>
+                                            <Text
+                                                as_element="p"
+                                                size="12px"
+                                                color="modalTextSecondary"
+                                            >
+                                                {status_label}
+                                            </Text>
+                                        </div>
+                                        {url.map(|url| view! {
+                                            <a
+                                                href=url
+                                                target="_blank"
+                                                rel="noopener noreferrer"
+                                                title=move || i18n.t("transaction.view")
+                                                style="color: var(--nk-colors-modalTextSecondary); text-decoration: none;"
+                                            >
+                                                "↗"
+                                            </a>
+                                        })}
+                                    </div>
+                                }
+                            }).collect_view()}
+                        </div>
+                    </Show>
+                </div>
+
                 // Disconnect button
                 <button
                     style="
@@ -92,3 +235,21 @@ pub fn AccountModal() -> impl IntoView {
         </Dialog>
     }
 }
+
+/// Spinner for pending transactions, a colored glyph otherwise
+fn status_icon(tx: &Transaction) -> AnyView {
+    let (glyph, color) = match tx.status {
+        TransactionStatus::Pending => {
+            return view! { <span class="nk-spinner" style="width: 16px; height: 16px;"></span> }.into_any();
+        }
+        TransactionStatus::Confirmed => ("✓", "var(--nk-colors-connectionIndicator)"),
+        TransactionStatus::Failed => ("✕", "var(--nk-colors-error)"),
+        TransactionStatus::Replaced { .. } => ("↻", "var(--nk-colors-modalTextSecondary)"),
+    };
+
+    view! {
+        <span style=format!("width: 16px; text-align: center; font-weight: 700; color: {};", color)>
+            {glyph}
+        </span>
+    }.into_any()
+}
@@ -0,0 +1,170 @@
+use leptos::prelude::*;
+use leptos::callback::{UnsyncCallback, Callback};
+use crate::components::primitives::{Dialog, Text, BoxFontWeight};
+use crate::error::ConnectError;
+use crate::state::modal::{use_modal_state, ModalType};
+use crate::state::connection::use_connection_state;
+use crate::hooks::use_wallet;
+use crate::i18n::use_i18n;
+use crate::utils::format::format_chain_name;
+use wasm_bindgen_futures::spawn_local;
+
+#[component]
+pub fn ChainModal() -> impl IntoView {
+    let modal_state = use_modal_state();
+    let connection_state = use_connection_state();
+    let wallet = use_wallet();
+    let i18n = use_i18n();
+
+    let is_open = modal_state.is_open(ModalType::Chain);
+    let on_close = UnsyncCallback::new(move |_| modal_state.close());
+
+    // Chain a switch request is currently pending for
+    let switching_to = RwSignal::new(None::<u64>);
+    // Why the last switch failed, until the next attempt
+    let switch_error = RwSignal::new(None::<ConnectError>);
+
+    let chains = StoredValue::new(connection_state.chains());
+
+    let handle_switch = {
+        let connection_state = connection_state.clone();
+        Callback::new(move |chain_id: u64| {
+            if switching_to.get_untracked().is_some() {
+                return;
+            }
+
+            let connection_state = connection_state.clone();
+            switching_to.set(Some(chain_id));
+            switch_error.set(None);
+            spawn_local(async move {
+                match connection_state.switch_chain(chain_id).await {
+                    Ok(_) => {
+                        log::info!("Switched to chain {}", chain_id);
+                        modal_state.close();
+                    }
+                    Err(e) => {
+                        log::error!("Failed to switch chain: {:?}", e);
+                        switch_error.set(Some(e));
+                    }
+                }
+                switching_to.set(None);
+            });
+        })
+    };
+
+    view! {
+        <Dialog open=is_open on_close=on_close>
+            <Show when=move || wallet.is_connected.get()>
+                <Text
+                    as_element="h2"
+                    size="24px"
+                    font_weight=BoxFontWeight::Bold
+                    color="modalText"
+                    additional_style="margin-bottom: 16px;"
+                >
+                    {move || i18n.t("chain_modal.title")}
+                </Text>
+
+                <Show when=move || wallet.is_wrong_network.get()>
+                    <Text
+                        as_element="p"
+                        size="14px"
+                        color="modalTextSecondary"
+                        additional_style="margin-bottom: 16px;"
+                    >
+                        {move || i18n.t("chain_modal.wrong_network_description")}
+                    </Text>
+                </Show>
+
+                // Chain list
+                <div style="display: flex; flex-direction: column; gap: 8px;">
+                    <For
+                        each=move || chains.get_value()
+                        key=|chain_id| *chain_id
+                        children=move |chain_id: u64| {
+                            let chain_name = format_chain_name(chain_id);
+                            let is_active = Signal::derive(move || wallet.chain_id.get() == Some(chain_id));
+                            let is_switching = Signal::derive(move || switching_to.get() == Some(chain_id));
+
+                            view! {
+                                <button
+                                    class="chain-option"
+                                    style=move || {
+                                        let base_style = "
+                                            display: flex;
+                                            align-items: center;
+                                            gap: 12px;
+                                            width: 100%;
+                                            padding: 12px 16px;
+                                            border-radius: var(--nk-radii-menuButton);
+                                            transition: all 0.125s ease;
+                                            font-family: var(--nk-fonts-body);
+                                            font-size: 16px;
+                                            font-weight: 600;
+                                        ";
+
+                                        if is_active.get() {
+                                            format!(
+                                                "{} background: var(--nk-colors-accentColor); \
+                                                 color: var(--nk-colors-accentColorForeground); \
+                                                 border: 1px solid var(--nk-colors-accentColor); cursor: default;",
+                                                base_style
+                                            )
+                                        } else if switching_to.get().is_some() {
+                                            format!(
+                                                "{} background: var(--nk-colors-modalBackground); \
+                                                 color: var(--nk-colors-modalText); \
+                                                 border: 1px solid var(--nk-colors-actionButtonBorder); \
+                                                 opacity: 0.6; cursor: wait;",
+                                                base_style
+                                            )
+                                        } else {
+                                            format!(
+                                                "{} background: var(--nk-colors-modalBackground); \
+                                                 color: var(--nk-colors-modalText); \
+                                                 border: 1px solid var(--nk-colors-actionButtonBorder); cursor: pointer;",
+                                                base_style
+                                            )
+                                        }
+                                    }
+                                    disabled=move || switching_to.get().is_some()
+                                    on:click=move |_| {
+                                        if !is_active.get_untracked() {
+                                            handle_switch.run(chain_id);
+                                        }
+                                    }
+                                >
+                                    <span style="flex: 1; text-align: left;">{chain_name.clone()}</span>
+
+                                    <Show when=move || is_active.get()>
+                                        <span style="display: flex; align-items: center; gap: 6px; font-size: 14px;">
+                                            {move || i18n.t("chain_modal.connected")}
+                                            <span style="
+                                                width: 8px;
+                                                height: 8px;
+                                                border-radius: 50%;
+                                                background: var(--nk-colors-connectionIndicator);
+                                            "></span>
+                                        </span>
+                                    </Show>
+
+                                    <Show when=move || is_switching.get()>
+                                        <span style="font-size: 14px; color: var(--nk-colors-modalTextSecondary);">
+                                            {move || i18n.t("chain_modal.switching")}
+                                        </span>
+                                    </Show>
+                                </button>
+                            }
+                        }
+                    />
+                </div>
+
+                <Show when=move || switch_error.get().is_some()>
+                    <Text as_element="p" size="14px" color="error" additional_style="margin-top: 16px;">
+                        {move || switch_error.get().map(|e| i18n.t(e.i18n_key())).unwrap_or_default()}
+                    </Text>
+                </Show>
+            </Show>
+        </Dialog>
+    }
+}
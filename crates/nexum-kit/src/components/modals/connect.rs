@@ -3,14 +3,18 @@ use leptos::callback::{UnsyncCallback, Callback};
 use crate::components::primitives::{Dialog, Text, BoxFontWeight};
 use crate::state::modal::{use_modal_state, ModalType};
 use crate::state::connection::use_connection_state;
-use crate::wallets::connectors::MetaMaskConnector;
-use crate::wallets::eip6963::{setup_eip6963_discovery, EIP6963ProviderInfo};
+use crate::hooks::use_wallet;
+use crate::i18n::use_i18n;
+use crate::wallets::connectors::Eip6963Connector;
+use crate::wallets::eip6963::{setup_eip6963_discovery, Eip6963Discovery, EIP6963Provider, EIP6963ProviderInfo};
 use wasm_bindgen_futures::spawn_local;
 
 #[component]
 pub fn ConnectModal() -> impl IntoView {
     let modal_state = use_modal_state();
     let connection_state = use_connection_state();
+    let wallet = use_wallet();
+    let i18n = use_i18n();
 
     let is_open = modal_state.is_open(ModalType::Connect);
     let on_close = UnsyncCallback::new(move |_| modal_state.close());
@@ -18,10 +22,16 @@ pub fn ConnectModal() -> impl IntoView {
     // Store just the provider info (not the JsValue provider)
     let discovered_wallets = RwSignal::new(Vec::<EIP6963ProviderInfo>::new());
 
+    // The provider objects aren't Send, so they live in local storage and are looked up by uuid
+    let discovered_providers = StoredValue::new_local(Vec::<EIP6963Provider>::new());
+
     // Setup EIP-6963 discovery when component mounts
     // Use a StoredValue to track if we've already set up discovery
     let discovery_setup = StoredValue::new(false);
 
+    // Keeps listening for late announcements until the modal is unmounted
+    let discovery = StoredValue::new_local(None::<Eip6963Discovery>);
+
     Effect::new(move |_| {
         // Only run once
         if discovery_setup.get_value() {
@@ -31,147 +41,247 @@ pub fn ConnectModal() -> impl IntoView {
 
         log::info!("Setting up EIP-6963 wallet discovery");
 
-        setup_eip6963_discovery(move |provider| {
+        let handle = setup_eip6963_discovery(move |provider| {
             log::info!("EIP-6963: Discovered wallet: {}", provider.info.name);
-            discovered_wallets.update(|wallets| {
-                // Avoid duplicates based on uuid
-                if !wallets.iter().any(|w| w.uuid == provider.info.uuid) {
-                    wallets.push(provider.info);
+            // Avoid duplicates based on uuid
+            if discovered_wallets.with_untracked(|wallets| wallets.iter().any(|w| w.uuid == provider.info.uuid)) {
+                return;
+            }
+
+            discovered_wallets.update(|wallets| wallets.push(provider.info.clone()));
+            discovered_providers.update_value(|providers| providers.push(provider));
+        });
+        discovery.set_value(handle);
+    });
+
+    // Wallet the user picked; while set, the detail pane replaces the wallet list
+    let selected_wallet = RwSignal::new(None::<EIP6963ProviderInfo>);
+
+    // Start from the wallet list whenever the modal is reopened
+    Effect::new(move |_| {
+        if !is_open.get() {
+            selected_wallet.set(None);
+        }
+    });
+
+    let handle_connect = {
+        let connection_state = connection_state.clone();
+        Callback::new(move |wallet_info: EIP6963ProviderInfo| {
+            // The open request decides the outcome; selecting another wallet would
+            // show "Opening" for a wallet that is never asked
+            if wallet.is_connecting.get_untracked() {
+                log::warn!("Connection already in progress, ignoring {}", wallet_info.name);
+                return;
+            }
+
+            // Connect through the provider the wallet announced
+            let Some(provider) = discovered_providers.with_value(|providers| {
+                providers.iter().find(|p| p.info.uuid == wallet_info.uuid).cloned()
+            }) else {
+                log::error!("No provider found for {}", wallet_info.name);
+                return;
+            };
+
+            let wallet_name = wallet_info.name.clone();
+            selected_wallet.set(Some(wallet_info));
+
+            let connector = Eip6963Connector::new(provider);
+            let connection_state = connection_state.clone();
+            spawn_local(async move {
+                log::info!("Attempting to connect to {} via EIP-6963...", wallet_name);
+                match connection_state.connect(&connector).await {
+                    Ok(_) => {
+                        log::info!("Successfully connected to {}!", wallet_name);
+                        modal_state.close();
+                    }
+                    Err(e) => {
+                        // Shown in the detail pane through `last_error`
+                        log::error!("Failed to connect: {:?}", e);
+                    }
                 }
             });
-        });
+        })
+    };
+
+    let last_error = connection_state.last_error;
+    let handle_back = Callback::new(move |_| {
+        last_error.set(None);
+        selected_wallet.set(None);
     });
 
+    let handle_retry = Callback::new(move |_| {
+        if let Some(wallet_info) = selected_wallet.get_untracked() {
+            handle_connect.run(wallet_info);
+        }
+    });
+
+    // The attempt for the selected wallet failed and can be retried
+    let has_failed = move || !wallet.is_connecting.get() && wallet.last_error.get().is_some();
+
     view! {
         <Dialog open=is_open on_close=on_close>
-            <Text
-                as_element="h2"
-                size="24px"
-                font_weight=BoxFontWeight::Bold
-                color="modalText"
-                additional_style="margin-bottom: 16px;"
-            >
-                "Connect a Wallet"
-            </Text>
-            <Text
-                as_element="p"
-                size="14px"
-                color="modalTextSecondary"
-                additional_style="margin-bottom: 24px;"
+            <Show
+                when=move || selected_wallet.get().is_none()
+                fallback=move || view! {
+                    // Connection detail pane for the selected wallet
+                    <div style="display: flex; flex-direction: column; align-items: center; text-align: center; gap: 12px;">
+                        <button
+                            style="
+                                align-self: flex-start;
+                                padding: 4px 8px;
+                                background: none;
+                                border: none;
+                                color: var(--nk-colors-accentColor);
+                                font-family: var(--nk-fonts-body);
+                                font-size: 16px;
+                                font-weight: 600;
+                                cursor: pointer;
+                            "
+                            on:click=move |ev| handle_back.run(ev)
+                        >
+                            {move || format!("← {}", i18n.t("common.back"))}
+                        </button>
+
+                        <img
+                            src=move || selected_wallet.get().map(|w| w.icon).unwrap_or_default()
+                            alt=move || selected_wallet.get().map(|w| format!("{} icon", w.name)).unwrap_or_default()
+                            style="width: 80px; height: 80px; border-radius: 16px; object-fit: contain; margin: 16px 0;"
+                        />
+
+                        <Text
+                            as_element="h3"
+                            size="18px"
+                            font_weight=BoxFontWeight::Bold
+                            color="modalText"
+                        >
+                            {move || {
+                                let name = selected_wallet.get().map(|w| w.name).unwrap_or_default();
+                                if has_failed() {
+                                    name
+                                } else {
+                                    i18n.t("connect_modal.opening").replace("{wallet}", &name)
+                                }
+                            }}
+                        </Text>
+
+                        <Show
+                            when=has_failed
+                            fallback=move || view! {
+                                <Text as_element="p" size="14px" color="modalTextSecondary">
+                                    {move || i18n.t("connect_modal.confirm_connection")}
+                                </Text>
+                            }
+                        >
+                            <Text as_element="p" size="14px" color="error">
+                                {move || wallet.last_error.get().map(|e| i18n.t(e.i18n_key())).unwrap_or_default()}
+                            </Text>
+
+                            <button
+                                class="nk-button nk-button-primary"
+                                style="margin-top: 8px;"
+                                on:click=move |ev| handle_retry.run(ev)
+                            >
+                                {move || i18n.t("connect_modal.retry")}
+                            </button>
+                        </Show>
+                    </div>
+                }
             >
-                "Choose how you want to connect. There are several wallet providers to choose from."
-            </Text>
-
-            // Wallet list
-            <div style="display: flex; flex-direction: column; gap: 12px;">
-                // Show discovered EIP-6963 wallets
-                <For
-                    each=move || discovered_wallets.get()
-                    key=|wallet| wallet.uuid.clone()
-                    children={
-                        let connection_state = connection_state.clone();
-                        let modal_state = modal_state.clone();
-                        move |wallet_info: EIP6963ProviderInfo| {
+                <Text
+                    as_element="h2"
+                    size="24px"
+                    font_weight=BoxFontWeight::Bold
+                    color="modalText"
+                    additional_style="margin-bottom: 16px;"
+                >
+                    "Connect a Wallet"
+                </Text>
+                <Text
+                    as_element="p"
+                    size="14px"
+                    color="modalTextSecondary"
+                    additional_style="margin-bottom: 24px;"
+                >
+                    "Choose how you want to connect. There are several wallet providers to choose from."
+                </Text>
+
+                // Wallet list
+                <div style="display: flex; flex-direction: column; gap: 12px;">
+                    // Show discovered EIP-6963 wallets
+                    <For
+                        each=move || discovered_wallets.get()
+                        key=|wallet| wallet.uuid.clone()
+                        children=move |wallet_info: EIP6963ProviderInfo| {
                             let wallet_name = wallet_info.name.clone();
                             let wallet_icon = wallet_info.icon.clone();
-                            let _wallet_rdns = wallet_info.rdns.clone(); // Will be used to select connector
-
-                            let handle_click = {
-                                let wallet_name = wallet_name.clone();
-                                let connection_state = connection_state.clone();
-                                let modal_state = modal_state.clone();
-                            Callback::new(move |_| {
-                                let wallet_name = wallet_name.clone();
-                                let connection_state = connection_state.clone();
-                                let modal_state = modal_state.clone();
-                                // Use the RDNS to identify which connector to use
-                                // For now, we only support MetaMask
-                                let connector = MetaMaskConnector::new();
-                                spawn_local(async move {
-                                    log::info!("Attempting to connect to {} via EIP-6963...", wallet_name);
-                                    match connection_state.connect(&connector).await {
-                                        Ok(_) => {
-                                            log::info!("Successfully connected to {}!", wallet_name);
-                                            modal_state.close();
-                                        }
-                                        Err(e) => {
-                                            log::error!("Failed to connect: {:?}", e);
+
+                            view! {
+                                <button
+                                    class="wallet-option"
+                                    style=move || {
+                                        let base_style = "
+                                            display: flex;
+                                            align-items: center;
+                                            gap: 12px;
+                                            width: 100%;
+                                            padding: 16px;
+                                            background: var(--nk-colors-modalBackground);
+                                            border: 1px solid var(--nk-colors-actionButtonBorder);
+                                            border-radius: var(--nk-radii-actionButton);
+                                            transition: all 0.125s ease;
+                                            font-family: var(--nk-fonts-body);
+                                            font-size: 16px;
+                                            font-weight: 600;
+                                            color: var(--nk-colors-modalText);
+                                        ";
+
+                                        if wallet.is_connecting.get() {
+                                            format!("{} opacity: 0.6; cursor: wait;", base_style)
+                                        } else {
+                                            format!("{} cursor: pointer;", base_style)
                                         }
                                     }
-                                });
-                            })
-                        };
+                                    disabled=move || wallet.is_connecting.get()
+                                    on:click=move |_| handle_connect.run(wallet_info.clone())
+                                >
+                                    // Wallet icon from EIP-6963 (actual icon from the wallet!)
+                                    <img
+                                        src=wallet_icon.clone()
+                                        alt=format!("{} icon", wallet_name.clone())
+                                        style="width: 40px; height: 40px; border-radius: 8px; object-fit: contain;"
+                                    />
 
-                        let connection_state_for_style = connection_state.clone();
-                            let connection_state_for_disabled = connection_state.clone();
+                                    <span style="flex: 1; text-align: left;">{wallet_name.clone()}</span>
 
-                            view! {
-                            <button
-                                class="wallet-option"
-                                style=move || {
-                                    let base_style = "
-                                        display: flex;
-                                        align-items: center;
-                                        gap: 12px;
-                                        width: 100%;
-                                        padding: 16px;
-                                        background: var(--nk-colors-modalBackground);
-                                        border: 1px solid var(--nk-colors-actionButtonBorder);
-                                        border-radius: var(--nk-radii-actionButton);
-                                        transition: all 0.125s ease;
-                                        font-family: var(--nk-fonts-body);
-                                        font-size: 16px;
+                                    // Show "Installed" badge for EIP-6963 wallets
+                                    <span style="
+                                        padding: 4px 8px;
+                                        background: var(--nk-colors-accentColor);
+                                        color: var(--nk-colors-accentColorForeground);
+                                        border-radius: 6px;
+                                        font-size: 12px;
                                         font-weight: 600;
-                                        color: var(--nk-colors-modalText);
-                                    ";
-
-                                    if connection_state_for_style.is_connecting() {
-                                        format!("{} opacity: 0.6; cursor: wait;", base_style)
-                                    } else {
-                                        format!("{} cursor: pointer;", base_style)
-                                    }
-                                }
-                                disabled=move || connection_state_for_disabled.is_connecting()
-                                on:click=move |ev| handle_click.run(ev)
-                            >
-                                // Wallet icon from EIP-6963 (actual icon from the wallet!)
-                                <img
-                                    src=wallet_icon.clone()
-                                    alt=format!("{} icon", wallet_name.clone())
-                                    style="width: 40px; height: 40px; border-radius: 8px; object-fit: contain;"
-                                />
-
-                                <span style="flex: 1; text-align: left;">{wallet_name.clone()}</span>
-
-                                // Show "Installed" badge for EIP-6963 wallets
-                                <span style="
-                                    padding: 4px 8px;
-                                    background: var(--nk-colors-accentColor);
-                                    color: var(--nk-colors-accentColorForeground);
-                                    border-radius: 6px;
-                                    font-size: 12px;
-                                    font-weight: 600;
-                                ">
-                                    "Installed"
-                                </span>
-                            </button>
-                        }
+                                    ">
+                                        "Installed"
+                                    </span>
+                                </button>
+                            }
                         }
-                    }
-                />
-
-                // Fallback: Show message if no wallets discovered
-                <Show when=move || discovered_wallets.get().is_empty()>
-                    <Text
-                        as_element="p"
-                        size="14px"
-                        color="modalTextSecondary"
-                        additional_style="text-align: center; margin-top: 8px; padding: 32px;"
-                    >
-                        "No wallets detected. Please install MetaMask or another Ethereum wallet."
-                    </Text>
-                </Show>
-            </div>
+                    />
+
+                    // Fallback: Show message if no wallets discovered
+                    <Show when=move || discovered_wallets.get().is_empty()>
+                        <Text
+                            as_element="p"
+                            size="14px"
+                            color="modalTextSecondary"
+                            additional_style="text-align: center; margin-top: 8px; padding: 32px;"
+                        >
+                            "No wallets detected. Please install MetaMask or another Ethereum wallet."
+                        </Text>
+                    </Show>
+                </div>
+            </Show>
         </Dialog>
     }
 }
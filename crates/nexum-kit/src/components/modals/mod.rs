@@ -1,5 +1,7 @@
 pub mod connect;
 pub mod account;
+pub mod chain;
 
 pub use connect::ConnectModal;
 pub use account::AccountModal;
+pub use chain::ChainModal;
@@ -1,13 +1,25 @@
 use leptos::prelude::*;
 use wasm_bindgen::JsCast;
 use web_sys::HtmlStyleElement;
+use std::cell::Cell;
 use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+use wasm_bindgen_futures::spawn_local;
 use crate::state::modal::provide_modal_state;
-use crate::state::connection::provide_connection_state;
+use crate::state::toast::provide_toast_state;
+use crate::state::connection::{provide_connection_state, ConnectionState, ConnectionStatus};
+use crate::wallets::connectors::{Eip6963Connector, MetaMaskConnector};
+use crate::wallets::eip6963::{setup_eip6963_discovery, Eip6963Discovery};
 use crate::state::transaction::provide_transaction_store;
+use crate::state::ens::{provide_ens_cache, EnsCacheConfig};
 use crate::theme::{Theme, ThemeOptions, LightTheme};
 use crate::i18n::{Locale, provide_i18n};
 
+/// How long a persisted EIP-6963 wallet has to announce itself before
+/// reconnecting gives up
+const RECONNECT_DISCOVERY_TIMEOUT: Duration = Duration::from_secs(1);
+
 #[component]
 pub fn NexumKitProvider<T: Theme + Clone + 'static>(
     /// RPC URL mappings for each chain (chain_id -> rpc_url)
@@ -22,13 +34,24 @@ pub fn NexumKitProvider<T: Theme + Clone + 'static>(
     #[prop(optional)] theme: Option<T>,
     #[prop(optional)] theme_options: Option<ThemeOptions>,
     #[prop(optional)] locale: Option<Locale>,
+    /// TTL and persistence of cached ENS lookups
+    #[prop(optional)] ens_cache: Option<EnsCacheConfig>,
     children: Children,
 ) -> impl IntoView where T: Default {
     // Provide modal state
     provide_modal_state();
 
+    // Provide toast state
+    provide_toast_state();
+
     // Provide connection state with transports
-    provide_connection_state(transports.clone());
+    let connection_state = provide_connection_state(transports.clone());
+
+    // Silently restore the previous session, if any
+    restore_connection(connection_state);
+
+    // Provide ENS cache, which resolves through the transports
+    provide_ens_cache(ens_cache.unwrap_or_default());
 
     // Provide transaction store
     provide_transaction_store();
@@ -81,10 +104,14 @@ pub fn NexumKitProviderSimple(
     transports: HashMap<u64, String>,
     #[prop(optional)] theme_options: Option<ThemeOptions>,
     #[prop(optional)] locale: Option<Locale>,
+    #[prop(optional)] ens_cache: Option<EnsCacheConfig>,
     children: Children,
 ) -> impl IntoView {
     provide_modal_state();
-    provide_connection_state(transports);
+    provide_toast_state();
+    let connection_state = provide_connection_state(transports);
+    restore_connection(connection_state);
+    provide_ens_cache(ens_cache.unwrap_or_default());
     provide_transaction_store();
     provide_i18n(locale.unwrap_or_default());
 
@@ -122,3 +149,85 @@ pub fn NexumKitProviderSimple(
         </div>
     }
 }
+
+/// Reconnect to the last connected wallet without prompting the user
+///
+/// Status is set to `Reconnecting` synchronously so the first render
+/// doesn't show "Connect Wallet" while the wallet is queried.
+///
+/// Wallets with an rdns are looked up through EIP-6963 discovery. Installed
+/// wallets announce synchronously, but one that announces late is still
+/// reconnected as long as the session hasn't changed in the meantime. If none
+/// announces within [`RECONNECT_DISCOVERY_TIMEOUT`], the status falls back
+/// to `Disconnected`.
+fn restore_connection(connection_state: ConnectionState) {
+    let Some(last) = connection_state.last_connection() else {
+        return;
+    };
+
+    log::info!("Restoring connection to {}", last.connector_id);
+    connection_state.status.set(ConnectionStatus::Reconnecting);
+
+    if let Some(rdns) = last.rdns.clone() {
+        let found = Rc::new(Cell::new(false));
+
+        // Listens for a late announcement until the wallet has been found
+        let discovery = StoredValue::new_local(None::<Eip6963Discovery>);
+
+        let handle = setup_eip6963_discovery({
+            let found = found.clone();
+            let rdns = rdns.clone();
+            let connection_state = connection_state.clone();
+            move |provider| {
+                if found.get() || provider.info.rdns != rdns {
+                    return;
+                }
+
+                // The user may have connected or disconnected since the page loaded
+                if connection_state.last_connection().and_then(|c| c.rdns).as_deref() != Some(rdns.as_str()) {
+                    return;
+                }
+
+                found.set(true);
+                let connection_state = connection_state.clone();
+                spawn_local(async move {
+                    let _ = connection_state.reconnect(&Eip6963Connector::new(provider)).await;
+                    discovery.set_value(None);
+                });
+            }
+        });
+
+        // Announced synchronously, the handle is no longer needed
+        if found.get() {
+            return;
+        }
+
+        discovery.set_value(handle);
+
+        set_timeout(move || {
+            if found.get() {
+                return;
+            }
+            discovery.set_value(None);
+
+            // Leave the status alone if the user started connecting meanwhile
+            if connection_state.status.get_untracked() == ConnectionStatus::Reconnecting {
+                log::warn!("Wallet {} didn't announce itself, not reconnecting", rdns);
+                connection_state.status.set(ConnectionStatus::Disconnected);
+            }
+        }, RECONNECT_DISCOVERY_TIMEOUT);
+        return;
+    }
+
+    spawn_local(async move {
+        match last.connector_id.as_str() {
+            "metamask" => {
+                let _ = connection_state.reconnect(&MetaMaskConnector::new()).await;
+            }
+            other => {
+                log::warn!("Unknown persisted connector: {}", other);
+                connection_state.status.set(ConnectionStatus::Disconnected);
+            }
+        }
+    });
+}
@@ -0,0 +1,193 @@
+use leptos::prelude::*;
+use alloy::primitives::TxHash;
+use std::collections::HashMap;
+use crate::state::toast::{use_toast_state, Toast, ToastKind, ToastState};
+use crate::state::transaction::{use_transaction_store, Transaction, TransactionStatus};
+use crate::hooks::use_wallet;
+use crate::i18n::{use_i18n, I18n};
+use crate::utils::format::explorer_tx_url;
+
+/// Renders toasts and pushes them for transaction and connection events
+///
+/// Mount it once, inside the `NexumKitProvider`.
+#[component]
+pub fn Toaster() -> impl IntoView {
+    let toast_state = use_toast_state();
+    let transaction_store = use_transaction_store();
+    let wallet = use_wallet();
+    let i18n = use_i18n();
+
+    // Status of every known transaction, to notice additions and changes.
+    // `None` until the first run, so stored transactions don't pop on load.
+    let known = StoredValue::new(None::<HashMap<TxHash, TransactionStatus>>);
+
+    Effect::new(move || {
+        let transactions = transaction_store.all_transactions();
+        let current: HashMap<TxHash, TransactionStatus> =
+            transactions.iter().map(|tx| (tx.hash, tx.status)).collect();
+
+        let Some(previous) = known.try_update_value(|known| known.replace(current)).flatten() else {
+            return;
+        };
+
+        for tx in &transactions {
+            let key = match (previous.get(&tx.hash), tx.status) {
+                (None, TransactionStatus::Pending) => "transaction.pending",
+                (Some(TransactionStatus::Pending), status) if status != TransactionStatus::Pending => status.i18n_key(),
+                _ => continue,
+            };
+            toast_state.push(transaction_toast(i18n, tx, key));
+        }
+    });
+
+    // Failed connection attempts
+    Effect::new(move || {
+        if let Some(error) = wallet.last_error.get() {
+            toast_state.push(
+                Toast::new(ToastKind::Error, i18n.t("error.connection_failed"))
+                    .with_description(i18n.t(error.i18n_key()))
+            );
+        }
+    });
+
+    view! {
+        <div
+            data-nk=""
+            aria-live="polite"
+            style="
+                position: fixed;
+                right: 16px;
+                bottom: 16px;
+                z-index: 2147483647;
+                display: flex;
+                flex-direction: column;
+                gap: 8px;
+                width: 360px;
+                max-width: calc(100vw - 32px);
+                pointer-events: none;
+            "
+        >
+            <For
+                each=move || toast_state.toasts().get()
+                key=|toast| toast.id
+                children=move |toast: Toast| view! { <ToastItem toast=toast toast_state=toast_state /> }
+            />
+        </div>
+    }
+}
+
+#[component]
+fn ToastItem(toast: Toast, toast_state: ToastState) -> impl IntoView {
+    let i18n = use_i18n();
+    let id = toast.id;
+
+    view! {
+        <div
+            class="animate-slideUpAndFadeIn"
+            role="status"
+            style="
+                display: flex;
+                align-items: flex-start;
+                gap: 12px;
+                padding: 12px 16px;
+                background: var(--nk-colors-modalBackground);
+                border: 1px solid var(--nk-colors-modalBorder);
+                border-radius: var(--nk-radii-menuButton);
+                box-shadow: var(--nk-shadows-dialog);
+                color: var(--nk-colors-modalText);
+                font-family: var(--nk-fonts-body);
+                pointer-events: auto;
+            "
+        >
+            {kind_icon(toast.kind)}
+            <div style="flex: 1; min-width: 0;">
+                <p style="margin: 0; font-size: 14px; font-weight: 700;">{toast.title}</p>
+                {toast.description.map(|description| view! {
+                    <p style="
+                        margin: 2px 0 0;
+                        font-size: 13px;
+                        color: var(--nk-colors-modalTextSecondary);
+                        overflow: hidden;
+                        text-overflow: ellipsis;
+                        white-space: nowrap;
+                    ">
+                        {description}
+                    </p>
+                })}
+                {toast.link.map(|(label, url)| view! {
+                    <a
+                        href=url
+                        target="_blank"
+                        rel="noopener noreferrer"
+                        style="
+                            display: inline-block;
+                            margin-top: 4px;
+                            font-size: 13px;
+                            font-weight: 600;
+                            color: var(--nk-colors-accentColor);
+                            text-decoration: none;
+                        "
+                    >
+                        {label}" ↗"
+                    </a>
+                })}
+            </div>
+            <button
+                aria-label=move || i18n.t("common.close")
+                style="
+                    background: none;
+                    border: none;
+                    padding: 0;
+                    font-size: 16px;
+                    line-height: 1;
+                    color: var(--nk-colors-closeButton);
+                    cursor: pointer;
+                "
+                on:click=move |_| toast_state.dismiss(id)
+            >
+                "×"
+            </button>
+        </div>
+    }
+}
+
+/// Toast for a transaction event, linking to the block explorer when possible
+fn transaction_toast(i18n: I18n, tx: &Transaction, key: &str) -> Toast {
+    let kind = match tx.status {
+        TransactionStatus::Pending => ToastKind::Pending,
+        TransactionStatus::Confirmed => ToastKind::Success,
+        TransactionStatus::Failed => ToastKind::Error,
+        TransactionStatus::Replaced { .. } => ToastKind::Info,
+    };
+
+    let mut toast = Toast::new(kind, i18n.t(key));
+    if let Some(description) = &tx.description {
+        toast = toast.with_description(description.clone());
+    }
+    if let Some(url) = explorer_tx_url(tx.chain_id, &tx.hash) {
+        toast = toast.with_link(i18n.t("transaction.view"), url);
+    }
+    toast
+}
+
+fn kind_icon(kind: ToastKind) -> AnyView {
+    let (glyph, color) = match kind {
+        ToastKind::Pending => {
+            return view! {
+                <span
+                    class="nk-spinner"
+                    style="width: 16px; height: 16px; margin-top: 2px; color: var(--nk-colors-accentColor);"
+                ></span>
+            }.into_any();
+        }
+        ToastKind::Success => ("✓", "var(--nk-colors-connectionIndicator)"),
+        ToastKind::Error => ("✕", "var(--nk-colors-error)"),
+        ToastKind::Info => ("i", "var(--nk-colors-accentColor)"),
+    };
+
+    view! {
+        <span style=format!("width: 16px; text-align: center; font-weight: 700; color: {};", color)>
+            {glyph}
+        </span>
+    }.into_any()
+}
@@ -0,0 +1,196 @@
+//! Errors surfaced by wallet connection and wallet requests
+//!
+//! [`ConnectError`] keeps the EIP-1193 error returned by the wallet intact, so callers
+//! can tell a user rejection apart from a configuration problem such as a missing RPC URL.
+//! [`RequestError`] does the same for requests made once connected, such as sending a
+//! transaction or signing a message.
+
+use alloy::primitives::Address;
+use alloy::transports::TransportError;
+use alloy_eip1193::Eip1193Error;
+use thiserror::Error;
+
+/// Error returned when connecting, reconnecting, disconnecting or switching chains
+#[derive(Debug, Clone, Error)]
+pub enum ConnectError {
+    /// The wallet rejected or failed the request
+    #[error(transparent)]
+    Wallet(#[from] Eip1193Error),
+
+    /// The wallet's provider isn't available in this browser
+    #[error("{0} is not installed")]
+    NotInstalled(String),
+
+    /// The wallet didn't return any account
+    #[error("No accounts returned from {0}")]
+    NoAccounts(String),
+
+    /// No RPC URL is configured for the chain
+    #[error("No RPC URL configured for chain {0}")]
+    MissingTransport(u64),
+
+    /// The configured RPC URL can't be parsed
+    #[error("Invalid RPC URL for chain {chain_id}: {reason}")]
+    InvalidRpcUrl {
+        /// Chain the URL is configured for
+        chain_id: u64,
+        /// Parser error
+        reason: String,
+    },
+
+    /// Another connection attempt is still in progress
+    #[error("Connection already in progress")]
+    AlreadyConnecting,
+
+    /// The operation requires a connected wallet
+    #[error("Wallet not connected")]
+    NotConnected,
+}
+
+impl ConnectError {
+    /// Check if the user rejected the request in their wallet
+    pub fn is_user_rejection(&self) -> bool {
+        matches!(self, Self::Wallet(e) if e.is_user_rejection())
+    }
+
+    /// Translation key of the message to show for this error
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// let i18n = use_i18n();
+    /// let message = i18n.t(error.i18n_key());
+    /// ```
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            Self::Wallet(Eip1193Error::UserRejectedRequest) => "error.user_rejected",
+            Self::Wallet(Eip1193Error::UnrecognizedChain(_)) | Self::MissingTransport(_) => "error.unsupported_chain",
+            Self::NotInstalled(_) => "error.not_installed",
+            Self::NoAccounts(_) => "error.no_accounts",
+            Self::InvalidRpcUrl { .. } => "error.invalid_rpc_url",
+            Self::AlreadyConnecting => "error.already_connecting",
+            Self::NotConnected => "error.not_connected",
+            Self::Wallet(_) => "error.connection_failed",
+        }
+    }
+}
+
+impl From<TransportError> for ConnectError {
+    fn from(err: TransportError) -> Self {
+        let error = Eip1193Error::from_transport_error(&err)
+            .unwrap_or_else(|| Eip1193Error::JsError(err.to_string()));
+        Self::Wallet(error)
+    }
+}
+
+/// Error returned by wallet requests made through the hooks, e.g. `use_send_transaction`
+#[derive(Debug, Clone, Error)]
+pub enum RequestError {
+    /// The wallet rejected or failed the request
+    #[error(transparent)]
+    Wallet(#[from] Eip1193Error),
+
+    /// The request requires a connected wallet
+    #[error("Wallet not connected")]
+    NotConnected,
+
+    /// The wallet returned a signature no address can be recovered from
+    #[error("Invalid signature: {0}")]
+    InvalidSignature(String),
+
+    /// A contract read the request depends on failed
+    #[error("Contract call failed: {0}")]
+    Contract(String),
+
+    /// The signature was made by another account than the connected one
+    #[error("Signature is from {recovered}, expected {expected}")]
+    SignerMismatch {
+        /// Connected account
+        expected: Address,
+        /// Account recovered from the signature
+        recovered: Address,
+    },
+}
+
+impl RequestError {
+    /// Check if the user rejected the request in their wallet
+    pub fn is_user_rejection(&self) -> bool {
+        matches!(self, Self::Wallet(e) if e.is_user_rejection())
+    }
+
+    /// Translation key of the message to show for this error
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            Self::Wallet(Eip1193Error::UserRejectedRequest) => "error.user_rejected",
+            Self::Wallet(Eip1193Error::UnrecognizedChain(_)) => "error.unsupported_chain",
+            Self::NotConnected => "error.not_connected",
+            Self::InvalidSignature(_) | Self::SignerMismatch { .. } => "error.invalid_signature",
+            Self::Wallet(_) | Self::Contract(_) => "error.request_failed",
+        }
+    }
+}
+
+impl From<TransportError> for RequestError {
+    fn from(err: TransportError) -> Self {
+        let error = Eip1193Error::from_transport_error(&err)
+            .unwrap_or_else(|| Eip1193Error::JsError(err.to_string()));
+        Self::Wallet(error)
+    }
+}
+
+impl From<alloy::contract::Error> for RequestError {
+    fn from(err: alloy::contract::Error) -> Self {
+        Self::Contract(err.to_string())
+    }
+}
+
+impl From<alloy::signers::Error> for RequestError {
+    fn from(err: alloy::signers::Error) -> Self {
+        // Eip1193Signer keeps the wallet's error as the source
+        if let alloy::signers::Error::Other(source) = &err
+            && let Some(error) = source.downcast_ref::<Eip1193Error>()
+        {
+            return Self::Wallet(error.clone());
+        }
+        Self::Wallet(Eip1193Error::JsError(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_i18n_key() {
+        assert_eq!(ConnectError::Wallet(Eip1193Error::UserRejectedRequest).i18n_key(), "error.user_rejected");
+        assert_eq!(ConnectError::MissingTransport(10).i18n_key(), "error.unsupported_chain");
+        assert_eq!(ConnectError::NotInstalled("MetaMask".into()).i18n_key(), "error.not_installed");
+        assert_eq!(ConnectError::AlreadyConnecting.i18n_key(), "error.already_connecting");
+        assert_eq!(ConnectError::Wallet(Eip1193Error::Disconnected).i18n_key(), "error.connection_failed");
+    }
+
+    #[test]
+    fn test_is_user_rejection() {
+        assert!(ConnectError::Wallet(Eip1193Error::UserRejectedRequest).is_user_rejection());
+        assert!(!ConnectError::NotConnected.is_user_rejection());
+    }
+
+    #[test]
+    fn test_request_error_i18n_key() {
+        assert_eq!(RequestError::Wallet(Eip1193Error::UserRejectedRequest).i18n_key(), "error.user_rejected");
+        assert_eq!(RequestError::NotConnected.i18n_key(), "error.not_connected");
+        assert_eq!(RequestError::Wallet(Eip1193Error::Disconnected).i18n_key(), "error.request_failed");
+        assert_eq!(
+            RequestError::SignerMismatch { expected: Address::ZERO, recovered: Address::repeat_byte(1) }.i18n_key(),
+            "error.invalid_signature"
+        );
+    }
+
+    #[test]
+    fn test_request_error_from_signer_error() {
+        let err = RequestError::from(alloy::signers::Error::other(Eip1193Error::UserRejectedRequest));
+        assert!(err.is_user_rejection());
+
+        let err = RequestError::from(alloy::signers::Error::message("boom"));
+        assert!(!err.is_user_rejection());
+    }
+}
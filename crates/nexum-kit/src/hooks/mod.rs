@@ -1,7 +1,17 @@
 pub mod use_wallet;
 pub mod use_balance;
 pub mod use_ens;
+pub mod use_toast;
+pub mod use_send_transaction;
+pub mod use_send_calls;
+pub mod use_sign_message;
+pub mod use_sign_typed_data;
 
 pub use use_wallet::use_wallet;
 pub use use_balance::use_balance;
-pub use use_ens::use_ens_name;
+pub use use_ens::{use_ens_name, use_ens_address, use_ens_avatar};
+pub use use_toast::use_toast;
+pub use use_send_transaction::{use_send_transaction, SendTransaction};
+pub use use_send_calls::{use_send_calls, SendCalls};
+pub use use_sign_message::{use_sign_message, SignMessage};
+pub use use_sign_typed_data::{use_sign_typed_data, SignTypedData};
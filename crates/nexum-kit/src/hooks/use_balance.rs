@@ -1,78 +1,133 @@
 use leptos::prelude::*;
 use leptos::task::spawn_local;
-use alloy::primitives::Address;
-use alloy::providers::{Provider, ProviderBuilder};
-use wasm_bindgen::JsValue;
+use alloy::primitives::{Address, U256};
+use alloy::sol;
+use alloy_eip1193::Chain;
+use crate::state::connection::{use_connection_state, WalletProvider};
+
+sol! {
+    /// Subset of the ERC-20 interface needed to display a token balance
+    #[sol(rpc)]
+    interface IERC20 {
+        function balanceOf(address owner) external view returns (uint256);
+        function decimals() external view returns (uint8);
+        function symbol() external view returns (string);
+    }
+}
 
 #[derive(Clone)]
 pub struct BalanceInfo {
-    pub value: Signal<Option<u128>>,
+    /// Raw balance in the smallest unit (wei for the native currency)
+    pub value: Signal<Option<U256>>,
+    /// Decimals of the native currency or token
+    pub decimals: Signal<Option<u8>>,
+    /// Symbol of the native currency or token, if known
+    pub symbol: Signal<Option<String>>,
     pub formatted: Signal<String>,
     pub is_loading: Signal<bool>,
 }
 
+/// Fetched balance together with the metadata needed to display it
+#[derive(Debug, Clone)]
+struct Balance {
+    value: U256,
+    decimals: u8,
+    symbol: Option<String>,
+}
+
 /// Hook to fetch and track the balance of an Ethereum address
 ///
+/// Uses the connected wallet's provider, so the balance is read from the chain
+/// the wallet is on. It is fetched again whenever the address or chain changes.
+///
 /// # Arguments
 /// * `address` - Signal containing the address to fetch balance for
-/// * `rpc_url` - RPC URL to use for fetching the balance
+/// * `token` - ERC-20 token to read the balance of, or `None` for the native currency
 ///
 /// # Returns
 /// `BalanceInfo` containing the raw balance, formatted balance, and loading state
 pub fn use_balance(
     address: Signal<Option<Address>>,
-    rpc_url: &'static str,
+    token: Option<Address>,
 ) -> BalanceInfo {
-    let (balance, set_balance) = signal(None::<u128>);
+    let state = use_connection_state();
+    let (balance, set_balance) = signal(None::<Balance>);
     let (is_loading, set_is_loading) = signal(false);
 
+    // Only the latest request may update the balance, so a slow response for the
+    // previous chain or address can't overwrite a newer one
+    let latest_request = StoredValue::new(0u64);
+
     Effect::new(move || {
-        if let Some(addr) = address.get() {
-            set_is_loading.set(true);
-
-            spawn_local(async move {
-                match fetch_balance(addr, rpc_url).await {
-                    Ok(bal) => {
-                        set_balance.set(Some(bal));
-                    }
-                    Err(e) => {
-                        log::error!("Failed to fetch balance: {:?}", e);
-                        set_balance.set(None);
-                    }
-                }
-                set_is_loading.set(false);
-            });
-        } else {
+        let chain_id = state.chain_id.get();
+        let provider = state.provider.get();
+
+        latest_request.update_value(|id| *id += 1);
+        let request = latest_request.get_value();
+
+        let (Some(addr), Some(chain_id), Some(provider)) = (address.get(), chain_id, provider) else {
             set_balance.set(None);
             set_is_loading.set(false);
-        }
+            return;
+        };
+
+        set_is_loading.set(true);
+
+        spawn_local(async move {
+            let result = fetch_balance(provider, chain_id, addr, token).await;
+            if latest_request.get_value() != request {
+                return;
+            }
+
+            match result {
+                Ok(bal) => {
+                    set_balance.set(Some(bal));
+                }
+                Err(e) => {
+                    log::error!("Failed to fetch balance: {:?}", e);
+                    set_balance.set(None);
+                }
+            }
+            set_is_loading.set(false);
+        });
     });
 
     let formatted = Signal::derive(move || {
         balance.get()
-            .map(|b| crate::utils::format::format_balance(b, 18))
+            .map(|b| crate::utils::format::format_balance(b.value, b.decimals))
             .unwrap_or_default()
     });
 
     BalanceInfo {
-        value: balance.into(),
+        value: Signal::derive(move || balance.get().map(|b| b.value)),
+        decimals: Signal::derive(move || balance.get().map(|b| b.decimals)),
+        symbol: Signal::derive(move || balance.get().and_then(|b| b.symbol)),
         formatted,
         is_loading: is_loading.into(),
     }
 }
 
-async fn fetch_balance(address: Address, rpc_url: &str) -> Result<u128, JsValue> {
-    let provider = ProviderBuilder::new()
-        .connect(rpc_url)
-        .await
-        .map_err(|e| JsValue::from_str(&format!("Failed to connect to provider: {:?}", e)))?;
+async fn fetch_balance(
+    provider: WalletProvider,
+    chain_id: u64,
+    address: Address,
+    token: Option<Address>,
+) -> Result<Balance, alloy::contract::Error> {
+    let Some(token) = token else {
+        let value = provider.get_balance(address).await?;
+        let symbol = Chain::from_id(chain_id)
+            .named()
+            .and_then(|chain| chain.native_currency_symbol())
+            .map(str::to_string);
+
+        return Ok(Balance { value, decimals: 18, symbol });
+    };
 
-    let balance = provider
-        .get_balance(address)
-        .await
-        .map_err(|e| JsValue::from_str(&format!("Failed to get balance: {:?}", e)))?;
+    // Token reads only need the HTTP transport underneath the wallet layer
+    let erc20 = IERC20::new(token, provider.root());
+    let value = erc20.balanceOf(address).call().await?;
+    let decimals = erc20.decimals().call().await?;
+    let symbol = erc20.symbol().call().await?;
 
-    // Convert U256 balance to u128
-    let balance_u128 = balance.to::<u128>();
-    Ok(balance_u128)
+    Ok(Balance { value, decimals, symbol: Some(symbol) })
 }
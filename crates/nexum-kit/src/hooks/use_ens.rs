@@ -1,5 +1,9 @@
 use leptos::prelude::*;
 use alloy::primitives::Address;
+use crate::state::ens::use_ens_cache;
+
+/// ENS names are resolved against the registry on mainnet
+const ENS_CHAIN_ID: u64 = 1;
 
 /// Hook to resolve an Ethereum address to its ENS name
 ///
@@ -10,28 +14,19 @@ use alloy::primitives::Address;
 /// Signal containing the ENS name if available
 ///
 /// # Note
-/// Currently returns None as Alloy's ENS support in WASM is limited.
-/// This is a placeholder for future implementation when WASM support improves.
+/// Lookups use the mainnet RPC URL from `transports` (chain 1). Without it, this
+/// always returns `None`. Only primary names whose forward record points back to
+/// the address are returned. Results are shared through the ENS cache.
 pub fn use_ens_name(address: Signal<Option<Address>>) -> Signal<Option<String>> {
-    let (ens_name, set_ens_name) = signal(None::<String>);
+    let cache = use_ens_cache();
 
     Effect::new(move || {
-        if let Some(_addr) = address.get() {
-            // TODO: Implement ENS lookup when Alloy WASM support improves
-            // For now, we return None
-            //
-            // Future implementation would:
-            // 1. Create an ENS provider (mainnet)
-            // 2. Call provider.lookup_address(addr)
-            // 3. Set the result
-
-            set_ens_name.set(None);
-        } else {
-            set_ens_name.set(None);
+        if let Some(addr) = address.get() {
+            cache.request_name(ENS_CHAIN_ID, addr);
         }
     });
 
-    ens_name.into()
+    Signal::derive(move || address.get().and_then(|addr| cache.name(ENS_CHAIN_ID, addr)))
 }
 
 /// Hook to resolve an ENS name to an Ethereum address
@@ -43,21 +38,18 @@ pub fn use_ens_name(address: Signal<Option<Address>>) -> Signal<Option<String>>
 /// Signal containing the Ethereum address if available
 ///
 /// # Note
-/// Currently returns None as Alloy's ENS support in WASM is limited.
-/// This is a placeholder for future implementation.
+/// The name is normalized (ENSIP-15) before resolving; invalid names resolve to `None`.
+/// Requires a mainnet RPC URL in `transports`.
 pub fn use_ens_address(ens_name: Signal<Option<String>>) -> Signal<Option<Address>> {
-    let (address, set_address) = signal(None::<Address>);
+    let cache = use_ens_cache();
 
     Effect::new(move || {
-        if let Some(_name) = ens_name.get() {
-            // TODO: Implement ENS resolution when Alloy WASM support improves
-            set_address.set(None);
-        } else {
-            set_address.set(None);
+        if let Some(name) = ens_name.get() {
+            cache.request_address(ENS_CHAIN_ID, &name);
         }
     });
 
-    address.into()
+    Signal::derive(move || ens_name.with(|name| cache.address(ENS_CHAIN_ID, name.as_deref()?)))
 }
 
 /// Hook to fetch an ENS avatar URL for an address
@@ -67,17 +59,18 @@ pub fn use_ens_address(ens_name: Signal<Option<String>>) -> Signal<Option<Addres
 ///
 /// # Returns
 /// Signal containing the avatar URL if available
+///
+/// # Note
+/// Reads the `avatar` text record of the address's primary name. NFT avatars
+/// (`eip155:1/erc721:...`) are only shown if the address owns the token.
 pub fn use_ens_avatar(address: Signal<Option<Address>>) -> Signal<Option<String>> {
-    let (avatar_url, set_avatar_url) = signal(None::<String>);
+    let cache = use_ens_cache();
 
     Effect::new(move || {
-        if let Some(_addr) = address.get() {
-            // TODO: Implement ENS avatar lookup
-            set_avatar_url.set(None);
-        } else {
-            set_avatar_url.set(None);
+        if let Some(addr) = address.get() {
+            cache.request_avatar(ENS_CHAIN_ID, addr);
         }
     });
 
-    avatar_url.into()
+    Signal::derive(move || address.get().and_then(|addr| cache.avatar(ENS_CHAIN_ID, addr)))
 }
@@ -0,0 +1,175 @@
+use leptos::prelude::*;
+use leptos::leptos_dom::helpers::IntervalHandle;
+use leptos::task::spawn_local;
+use alloy::network::Ethereum;
+use alloy::providers::RootProvider;
+use std::time::Duration;
+use crate::error::RequestError;
+use crate::provider::{Call, CallsStatus, Eip1193, SendCallsRequest};
+use crate::state::connection::{use_connection_state, ConnectionState};
+
+/// How often the status of a sent batch is checked until it's final
+const CALLS_STATUS_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Batch state returned by [`use_send_calls`]
+#[derive(Clone, Copy)]
+pub struct SendCalls {
+    /// Dispatch with an EIP-5792 `wallet_sendCalls` request
+    pub action: Action<SendCallsRequest, Result<String, RequestError>>,
+    /// Whether the wallet is still handling the last request
+    pub pending: Signal<bool>,
+    /// Identifier of the last sent batch
+    pub id: Signal<Option<String>>,
+    /// Latest status of the last sent batch, polled until it's final
+    pub status: Signal<Option<CallsStatus>>,
+    /// Why the last request failed, if it did
+    pub error: Signal<Option<RequestError>>,
+    /// Chain the connected wallet is on
+    chain_id: RwSignal<Option<u64>>,
+}
+
+impl SendCalls {
+    /// Send `calls` as a batch on the connected chain
+    pub fn send(&self, calls: Vec<Call>) {
+        let chain_id = self.chain_id.get_untracked().unwrap_or_default();
+        self.action.dispatch(SendCallsRequest::new(chain_id, calls));
+    }
+
+    /// Send a batch with custom options, e.g. requiring atomic execution or a paymaster
+    pub fn send_request(&self, request: SendCallsRequest) {
+        self.action.dispatch(request);
+    }
+}
+
+/// Hook to send batches of calls through the connected wallet (EIP-5792)
+///
+/// # Returns
+/// An action to dispatch plus reactive `pending`, `id`, `status` and `error` signals
+///
+/// # Note
+/// `from` defaults to the connected account. Once the wallet accepts the batch, its
+/// status is polled with `wallet_getCallsStatus` until it's confirmed, failed or
+/// reverted. Use `wallet_getCapabilities` to check the wallet supports batches first.
+///
+/// # Example
+/// ```rust,ignore
+/// let send_calls = use_send_calls();
+/// send_calls.send(vec![
+///     Call::new(token).with_data(approve_calldata),
+///     Call::new(router).with_data(swap_calldata),
+/// ]);
+/// ```
+pub fn use_send_calls() -> SendCalls {
+    let state = use_connection_state();
+    let chain_id = state.chain_id;
+
+    let action = Action::new_local({
+        let state = state.clone();
+        move |request: &SendCallsRequest| {
+            let transport = state.wallet_transport.get_untracked();
+            let address = state.address.get_untracked();
+            let mut request = request.clone();
+
+            async move {
+                let (Some(transport), Some(address)) = (transport, address) else {
+                    return Err(RequestError::NotConnected);
+                };
+                request.from.get_or_insert(address);
+
+                let provider = RootProvider::<Ethereum>::new(transport.into_client());
+                let result = provider.send_calls(request).await.map_err(|e| {
+                    log::error!("Failed to send calls: {}", e);
+                    RequestError::from(e)
+                })?;
+                log::info!("Calls sent: {}", result.id);
+
+                Ok(result.id)
+            }
+        }
+    });
+
+    let value = action.value();
+    let id = Signal::derive(move || value.get().and_then(|result| result.ok()));
+    let error = Signal::derive(move || value.get().and_then(|result| result.err()));
+
+    let status = RwSignal::new(None::<CallsStatus>);
+    let is_final = Memo::new(move |_| status.with(|status| status.as_ref().is_some_and(CallsStatus::is_final)));
+    let interval = StoredValue::new_local(None::<IntervalHandle>);
+    let polling = StoredValue::new(false);
+
+    // Poll the status of the current batch until it's final
+    Effect::new(move || {
+        let batch_id = id.get();
+
+        if let Some(handle) = interval.try_update_value(Option::take).flatten() {
+            handle.clear();
+        }
+
+        let Some(batch_id) = batch_id else {
+            status.set(None);
+            return;
+        };
+        if status.with_untracked(|status| status.as_ref().is_some_and(|status| status.id != batch_id)) {
+            status.set(None);
+        }
+        if is_final.get() {
+            return;
+        }
+
+        let state = state.clone();
+        let poll = move || poll_calls_status(&state, batch_id.clone(), id, status, polling);
+        poll();
+
+        match set_interval_with_handle(poll, CALLS_STATUS_POLL_INTERVAL) {
+            Ok(handle) => interval.set_value(Some(handle)),
+            Err(e) => log::error!("Failed to start calls status polling: {:?}", e),
+        }
+    });
+
+    on_cleanup(move || {
+        if let Some(handle) = interval.try_update_value(Option::take).flatten() {
+            handle.clear();
+        }
+    });
+
+    SendCalls {
+        action,
+        pending: action.pending().into(),
+        id,
+        status: status.into(),
+        error,
+        chain_id,
+    }
+}
+
+/// Fetch the status of batch `id`, unless a previous check is still running
+fn poll_calls_status(
+    state: &ConnectionState,
+    id: String,
+    current: Signal<Option<String>>,
+    status: RwSignal<Option<CallsStatus>>,
+    polling: StoredValue<bool>,
+) {
+    let Some(transport) = state.wallet_transport.get_untracked() else {
+        return;
+    };
+    if polling.get_value() {
+        return;
+    }
+    polling.set_value(true);
+
+    spawn_local(async move {
+        let provider = RootProvider::<Ethereum>::new(transport.into_client());
+
+        match provider.get_calls_status(&id).await {
+            // A newer batch may have been sent in the meantime
+            Ok(fetched) if current.get_untracked().as_ref() == Some(&id) => {
+                status.set(Some(fetched));
+            }
+            Ok(_) => {}
+            Err(e) => log::warn!("Failed to fetch status of calls {}: {}", id, e),
+        }
+
+        polling.set_value(false);
+    });
+}
@@ -0,0 +1,132 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use alloy::primitives::TxHash;
+use alloy::rpc::types::{TransactionReceipt, TransactionRequest};
+use crate::error::RequestError;
+use crate::state::connection::use_connection_state;
+use crate::state::transaction::{use_transaction_store, Transaction, TransactionStatus};
+
+/// Input of the send action: the transaction and an optional description for
+/// the transaction history
+pub type SendTransactionInput = (TransactionRequest, Option<String>);
+
+/// Sending state returned by [`use_send_transaction`]
+#[derive(Clone, Copy)]
+pub struct SendTransaction {
+    /// Dispatch with a transaction and optional description
+    pub action: Action<SendTransactionInput, Result<TxHash, RequestError>>,
+    /// Whether the wallet is still handling the last request
+    pub pending: Signal<bool>,
+    /// Hash of the last sent transaction
+    pub hash: Signal<Option<TxHash>>,
+    /// Why the last request failed, if it did
+    pub error: Signal<Option<RequestError>>,
+    /// Receipt of the last sent transaction, once it's mined
+    pub receipt: Signal<Option<TransactionReceipt>>,
+}
+
+impl SendTransaction {
+    /// Send a transaction through the connected wallet
+    pub fn send(&self, tx: TransactionRequest, description: Option<String>) {
+        self.action.dispatch((tx, description));
+    }
+}
+
+/// Hook to send transactions through the connected wallet
+///
+/// # Returns
+/// An action to dispatch plus reactive `pending`, `hash`, `error` and `receipt` signals
+///
+/// # Note
+/// Sent transactions are recorded in the `TransactionStore`, so they appear in the
+/// account modal and their receipts are tracked. `from` defaults to the connected
+/// account.
+///
+/// # Example
+/// ```rust,ignore
+/// let send_tx = use_send_transaction();
+/// let tx = TransactionRequest::default().to(recipient).value(U256::from(1));
+/// send_tx.send(tx, Some("Send 1 wei".to_string()));
+/// ```
+pub fn use_send_transaction() -> SendTransaction {
+    let state = use_connection_state();
+    let transaction_store = use_transaction_store();
+
+    let action = Action::new_local(move |(tx, description): &SendTransactionInput| {
+        let provider = state.provider.get_untracked();
+        let address = state.address.get_untracked();
+        let chain_id = state.chain_id.get_untracked();
+        let mut tx = tx.clone();
+        let description = description.clone();
+
+        async move {
+            let (Some(provider), Some(address), Some(chain_id)) = (provider, address, chain_id) else {
+                return Err(RequestError::NotConnected);
+            };
+
+            let from = *tx.from.get_or_insert(address);
+            let to = tx.to.and_then(|kind| kind.to().copied());
+            let nonce = tx.nonce;
+
+            let pending_tx = provider.send_transaction(tx).await.map_err(|e| {
+                log::error!("Failed to send transaction: {}", e);
+                RequestError::from(e)
+            })?;
+            let hash = *pending_tx.tx_hash();
+            log::info!("Transaction sent: {}", hash);
+
+            let mut record = Transaction::new(hash, chain_id, from, to);
+            record.nonce = nonce;
+            record.description = description;
+            transaction_store.add_transaction(record);
+
+            Ok(hash)
+        }
+    });
+
+    let value = action.value();
+    let hash = Signal::derive(move || value.get().and_then(|result| result.ok()));
+    let error = Signal::derive(move || value.get().and_then(|result| result.err()));
+
+    // Fetch the receipt once the transaction store sees the transaction settle.
+    // Memos keep confirmation updates to the store from refetching it.
+    let current = Memo::new(move |_| hash.get());
+    let settled = Memo::new(move |_| {
+        current.get()
+            .and_then(|hash| transaction_store.find_transaction(hash))
+            .is_some_and(|tx| matches!(tx.status, TransactionStatus::Confirmed | TransactionStatus::Failed))
+    });
+
+    let receipt = RwSignal::new(None::<TransactionReceipt>);
+    Effect::new(move || {
+        current.track();
+        receipt.set(None);
+    });
+    Effect::new(move || {
+        let (Some(hash), true) = (current.get(), settled.get()) else {
+            return;
+        };
+        let Some(provider) = state.provider.get_untracked() else {
+            return;
+        };
+
+        spawn_local(async move {
+            match provider.get_transaction_receipt(hash).await {
+                // A newer transaction may have been sent in the meantime
+                Ok(Some(fetched)) if value.get_untracked().and_then(|r| r.ok()) == Some(hash) => {
+                    receipt.set(Some(fetched));
+                }
+                Ok(_) => {}
+                Err(e) => log::warn!("Failed to fetch receipt for {}: {}", hash, e),
+            }
+        });
+    });
+
+    SendTransaction {
+        action,
+        pending: action.pending().into(),
+        hash,
+        error,
+        receipt: receipt.into(),
+    }
+}
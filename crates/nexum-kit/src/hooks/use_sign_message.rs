@@ -0,0 +1,115 @@
+use leptos::prelude::*;
+use alloy::primitives::{eip191_hash_message, Address, FixedBytes, Signature, B256};
+use alloy::signers::Signer;
+use alloy::sol;
+use crate::error::RequestError;
+use crate::state::connection::{use_connection_state, WalletProvider};
+
+sol! {
+    /// EIP-1271 signature validation of smart contract accounts
+    #[sol(rpc)]
+    interface IERC1271 {
+        function isValidSignature(bytes32 hash, bytes signature) external view returns (bytes4 magicValue);
+    }
+}
+
+/// Value `isValidSignature` returns for a valid signature
+const EIP1271_MAGIC_VALUE: FixedBytes<4> = FixedBytes([0x16, 0x26, 0xba, 0x7e]);
+
+/// Signing state returned by [`use_sign_message`]
+#[derive(Clone, Copy)]
+pub struct SignMessage {
+    /// Dispatch with the message to sign
+    pub action: Action<Vec<u8>, Result<Signature, RequestError>>,
+    /// Whether the wallet is still handling the last request
+    pub pending: Signal<bool>,
+    /// Signature of the last message, once verified
+    pub signature: Signal<Option<Signature>>,
+    /// Why the last request failed, if it did
+    pub error: Signal<Option<RequestError>>,
+}
+
+impl SignMessage {
+    /// Ask the connected wallet to sign `message` with `personal_sign`
+    pub fn sign(&self, message: impl Into<Vec<u8>>) {
+        self.action.dispatch(message.into());
+    }
+}
+
+/// Hook to sign messages with the connected wallet
+///
+/// # Returns
+/// An action to dispatch plus reactive `pending`, `signature` and `error` signals
+///
+/// # Note
+/// The signature is only reported once the address recovered from it matches the
+/// connected account, or the account is a smart contract account (EIP-1271) that
+/// accepts it; otherwise `error` is set to [`RequestError::SignerMismatch`].
+/// Signatures must be 65 bytes, so accounts returning longer ones can't sign.
+///
+/// # Example
+/// ```rust,ignore
+/// let sign_message = use_sign_message();
+/// sign_message.sign("Hello from Nexum-Kit!");
+/// ```
+pub fn use_sign_message() -> SignMessage {
+    let state = use_connection_state();
+
+    let action = Action::new_local(move |message: &Vec<u8>| {
+        let signer = state.signer();
+        let provider = state.provider.get_untracked();
+        let message = message.clone();
+
+        async move {
+            let signer = signer.ok_or(RequestError::NotConnected)?;
+
+            let signature = signer.sign_message(&message).await.map_err(|e| {
+                log::error!("Failed to sign message: {}", e);
+                RequestError::from(e)
+            })?;
+
+            verify_signer(provider, signer.address(), eip191_hash_message(&message), &signature).await?;
+
+            Ok(signature)
+        }
+    });
+
+    let value = action.value();
+
+    SignMessage {
+        action,
+        pending: action.pending().into(),
+        signature: Signal::derive(move || value.get().and_then(|result| result.ok())),
+        error: Signal::derive(move || value.get().and_then(|result| result.err())),
+    }
+}
+
+/// Check that `signature` of `hash` was made by `expected`
+///
+/// If another address is recovered, `expected` may be a smart contract account,
+/// so its EIP-1271 `isValidSignature` gets the final say.
+pub(crate) async fn verify_signer(
+    provider: Option<WalletProvider>,
+    expected: Address,
+    hash: B256,
+    signature: &Signature,
+) -> Result<(), RequestError> {
+    let recovered = signature
+        .recover_address_from_prehash(&hash)
+        .map_err(|e| RequestError::InvalidSignature(e.to_string()))?;
+    if recovered == expected {
+        return Ok(());
+    }
+
+    if let Some(provider) = provider {
+        let account = IERC1271::new(expected, provider.root());
+        match account.isValidSignature(hash, signature.as_bytes().into()).call().await {
+            Ok(magic_value) if magic_value == EIP1271_MAGIC_VALUE => return Ok(()),
+            Ok(_) => {}
+            // Accounts without code can't decode a return value
+            Err(e) => log::debug!("EIP-1271 check of {} failed: {}", expected, e),
+        }
+    }
+
+    Err(RequestError::SignerMismatch { expected, recovered })
+}
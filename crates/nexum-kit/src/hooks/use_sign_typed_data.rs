@@ -0,0 +1,79 @@
+use leptos::prelude::*;
+use alloy::dyn_abi::eip712::TypedData;
+use alloy::primitives::Signature;
+use alloy::signers::Signer;
+use crate::error::RequestError;
+use crate::hooks::use_sign_message::verify_signer;
+use crate::state::connection::use_connection_state;
+
+/// Signing state returned by [`use_sign_typed_data`]
+#[derive(Clone, Copy)]
+pub struct SignTypedData {
+    /// Dispatch with the EIP-712 typed data to sign
+    pub action: Action<TypedData, Result<Signature, RequestError>>,
+    /// Whether the wallet is still handling the last request
+    pub pending: Signal<bool>,
+    /// Signature of the last typed data, once verified
+    pub signature: Signal<Option<Signature>>,
+    /// Why the last request failed, if it did
+    pub error: Signal<Option<RequestError>>,
+}
+
+impl SignTypedData {
+    /// Ask the connected wallet to sign `typed_data` with `eth_signTypedData_v4`
+    pub fn sign(&self, typed_data: TypedData) {
+        self.action.dispatch(typed_data);
+    }
+}
+
+/// Hook to sign EIP-712 typed data with the connected wallet
+///
+/// # Returns
+/// An action to dispatch plus reactive `pending`, `signature` and `error` signals
+///
+/// # Note
+/// The signature is only reported once the address recovered from it matches the
+/// connected account, or the account is a smart contract account (EIP-1271) that
+/// accepts it; otherwise `error` is set to [`RequestError::SignerMismatch`].
+/// Signatures must be 65 bytes, so accounts returning longer ones can't sign.
+///
+/// # Example
+/// ```rust,ignore
+/// let sign_typed_data = use_sign_typed_data();
+/// let typed_data: TypedData = serde_json::from_value(json)?;
+/// sign_typed_data.sign(typed_data);
+/// ```
+pub fn use_sign_typed_data() -> SignTypedData {
+    let state = use_connection_state();
+
+    let action = Action::new_local(move |typed_data: &TypedData| {
+        let signer = state.signer();
+        let provider = state.provider.get_untracked();
+        let typed_data = typed_data.clone();
+
+        async move {
+            let signer = signer.ok_or(RequestError::NotConnected)?;
+
+            let signature = signer.sign_dynamic_typed_data(&typed_data).await.map_err(|e| {
+                log::error!("Failed to sign typed data: {}", e);
+                RequestError::from(e)
+            })?;
+
+            let hash = typed_data
+                .eip712_signing_hash()
+                .map_err(|e| RequestError::InvalidSignature(e.to_string()))?;
+            verify_signer(provider, signer.address(), hash, &signature).await?;
+
+            Ok(signature)
+        }
+    });
+
+    let value = action.value();
+
+    SignTypedData {
+        action,
+        pending: action.pending().into(),
+        signature: Signal::derive(move || value.get().and_then(|result| result.ok())),
+        error: Signal::derive(move || value.get().and_then(|result| result.err())),
+    }
+}
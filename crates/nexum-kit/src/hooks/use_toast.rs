@@ -0,0 +1,19 @@
+use crate::state::toast::{use_toast_state, ToastState};
+
+/// Hook to show toast notifications
+///
+/// # Returns
+/// Handle for pushing and dismissing toasts
+///
+/// # Note
+/// Toasts are rendered by the `Toaster` component, which must be mounted inside
+/// the `NexumKitProvider`. Transaction and connection toasts are pushed automatically.
+///
+/// # Example
+/// ```rust,ignore
+/// let toast = use_toast();
+/// toast.push(Toast::new(ToastKind::Success, "Profile saved").with_description("Changes are live"));
+/// ```
+pub fn use_toast() -> ToastState {
+    use_toast_state()
+}
@@ -1,5 +1,6 @@
 use leptos::prelude::*;
 use crate::state::connection::{use_connection_state, ConnectionStatus, WalletProvider};
+use crate::error::ConnectError;
 use alloy::primitives::Address;
 
 /// Wallet information including address, connection status, and Alloy provider
@@ -15,7 +16,17 @@ pub struct WalletInfo {
     pub chain_id: Signal<Option<u64>>,
     pub is_connected: Signal<bool>,
     pub is_connecting: Signal<bool>,
+    /// Whether a previous session is being silently restored on page load
+    pub is_reconnecting: Signal<bool>,
+    /// Whether the wallet is connected to a chain not configured in `transports`
+    ///
+    /// While this is true `provider` is `None`; switch chains via the chain modal.
+    pub is_wrong_network: Signal<bool>,
     pub connector_id: Signal<Option<String>>,
+    /// Why the last connection attempt failed, if it did
+    ///
+    /// Use `ConnectError::i18n_key` to display it, e.g. `error.user_rejected`.
+    pub last_error: Signal<Option<ConnectError>>,
     /// Alloy provider combining HTTP transport + EIP-1193 signer
     ///
     /// This provider implements the full Alloy `Provider` trait, so you can:
@@ -24,7 +35,9 @@ pub struct WalletInfo {
     /// - Interact with contracts: Use with Alloy contract bindings
     /// - Everything else Alloy supports
     ///
-    /// Note: Chain changes are automatically synced via EIP-1193 events
+    /// Note: Chain changes are automatically synced via EIP-1193 events. The provider
+    /// is rebuilt for the new chain's RPC URL, and is `None` while the wallet is on a
+    /// chain without a configured transport.
     pub provider: Signal<Option<WalletProvider>>,
 }
 
@@ -57,10 +70,25 @@ impl WalletInfo {
         self.is_connecting.get_untracked()
     }
 
+    /// Check if reconnecting without reactive tracking
+    pub fn is_reconnecting_untracked(&self) -> bool {
+        self.is_reconnecting.get_untracked()
+    }
+
+    /// Check if on the wrong network without reactive tracking
+    pub fn is_wrong_network_untracked(&self) -> bool {
+        self.is_wrong_network.get_untracked()
+    }
+
     /// Get the connector ID without reactive tracking
     pub fn connector_id_untracked(&self) -> Option<String> {
         self.connector_id.get_untracked()
     }
+
+    /// Get the last connection error without reactive tracking
+    pub fn last_error_untracked(&self) -> Option<ConnectError> {
+        self.last_error.get_untracked()
+    }
 }
 
 /// Hook to access wallet connection information and Alloy provider
@@ -106,7 +134,13 @@ pub fn use_wallet() -> WalletInfo {
     let chain_id = Signal::derive(move || state.chain_id.get());
     let is_connected = Signal::derive(move || state.status.get() == ConnectionStatus::Connected);
     let is_connecting = Signal::derive(move || state.status.get() == ConnectionStatus::Connecting);
+    let is_reconnecting = Signal::derive(move || state.status.get() == ConnectionStatus::Reconnecting);
+    let is_wrong_network = {
+        let state = state.clone();
+        Signal::derive(move || state.is_wrong_network())
+    };
     let connector_id = Signal::derive(move || state.connector_id.get());
+    let last_error = Signal::derive(move || state.last_error.get());
     let provider = Signal::derive(move || state.provider.get());
 
     WalletInfo {
@@ -114,7 +148,10 @@ pub fn use_wallet() -> WalletInfo {
         chain_id,
         is_connected,
         is_connecting,
+        is_reconnecting,
+        is_wrong_network,
         connector_id,
+        last_error,
         provider,
     }
 }
@@ -14,6 +14,9 @@ pub fn translations() -> HashMap<&'static str, &'static str> {
     map.insert("connect_modal.get_wallet", "I don't have a wallet");
     map.insert("connect_modal.install_extension", "Install Extension");
     map.insert("connect_modal.not_available", "Not available");
+    map.insert("connect_modal.opening", "Opening {wallet}...");
+    map.insert("connect_modal.confirm_connection", "Confirm connection in the extension");
+    map.insert("connect_modal.retry", "Retry");
 
     // Account modal
     map.insert("account_modal.title", "Account");
@@ -21,18 +24,32 @@ pub fn translations() -> HashMap<&'static str, &'static str> {
     map.insert("account_modal.copy_address", "Copy Address");
     map.insert("account_modal.copied", "Copied!");
     map.insert("account_modal.view_explorer", "View on Explorer");
+    map.insert("account_modal.recent_transactions", "Recent Transactions");
+    map.insert("account_modal.no_transactions", "Your transactions will appear here");
+    map.insert("account_modal.clear_all", "Clear all");
 
     // Chain modal
     map.insert("chain_modal.title", "Switch Networks");
     map.insert("chain_modal.wrong_network", "Wrong network");
+    map.insert("chain_modal.wrong_network_description", "Wrong network detected, switch or disconnect to continue.");
     map.insert("chain_modal.switching", "Switching...");
+    map.insert("chain_modal.connected", "Connected");
 
     // Transaction status
     map.insert("transaction.pending", "Transaction pending");
     map.insert("transaction.confirmed", "Transaction confirmed");
     map.insert("transaction.failed", "Transaction failed");
+    map.insert("transaction.sped_up", "Transaction sped up");
+    map.insert("transaction.cancelled", "Transaction cancelled");
+    map.insert("transaction.replaced", "Transaction replaced");
     map.insert("transaction.view", "View transaction");
 
+    // Add token
+    map.insert("add_token.label", "Add to wallet");
+    map.insert("add_token.adding", "Adding...");
+    map.insert("add_token.added", "{symbol} added to your wallet");
+    map.insert("add_token.failed", "Couldn't add the token");
+
     // Wallet names
     map.insert("wallet.metamask", "MetaMask");
     map.insert("wallet.walletconnect", "WalletConnect");
@@ -45,6 +62,12 @@ pub fn translations() -> HashMap<&'static str, &'static str> {
     map.insert("error.user_rejected", "User rejected the request");
     map.insert("error.not_installed", "Wallet not installed");
     map.insert("error.unsupported_chain", "Unsupported chain");
+    map.insert("error.no_accounts", "No accounts available in the wallet");
+    map.insert("error.invalid_rpc_url", "Invalid RPC URL");
+    map.insert("error.already_connecting", "A connection request is already pending");
+    map.insert("error.not_connected", "Wallet not connected");
+    map.insert("error.request_failed", "Request failed");
+    map.insert("error.invalid_signature", "The signature doesn't match your account");
 
     // Common
     map.insert("common.cancel", "Cancel");
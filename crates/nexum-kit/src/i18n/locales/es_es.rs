@@ -14,6 +14,9 @@ pub fn translations() -> HashMap<&'static str, &'static str> {
     map.insert("connect_modal.get_wallet", "No tengo una billetera");
     map.insert("connect_modal.install_extension", "Instalar Extensión");
     map.insert("connect_modal.not_available", "No disponible");
+    map.insert("connect_modal.opening", "Abriendo {wallet}...");
+    map.insert("connect_modal.confirm_connection", "Confirma la conexión en la extensión");
+    map.insert("connect_modal.retry", "Reintentar");
 
     // Account modal
     map.insert("account_modal.title", "Cuenta");
@@ -21,18 +24,32 @@ pub fn translations() -> HashMap<&'static str, &'static str> {
     map.insert("account_modal.copy_address", "Copiar Dirección");
     map.insert("account_modal.copied", "¡Copiado!");
     map.insert("account_modal.view_explorer", "Ver en Explorador");
+    map.insert("account_modal.recent_transactions", "Transacciones Recientes");
+    map.insert("account_modal.no_transactions", "Tus transacciones aparecerán aquí");
+    map.insert("account_modal.clear_all", "Borrar todo");
 
     // Chain modal
     map.insert("chain_modal.title", "Cambiar Redes");
     map.insert("chain_modal.wrong_network", "Red incorrecta");
+    map.insert("chain_modal.wrong_network_description", "Red incorrecta detectada, cambia o desconecta para continuar.");
     map.insert("chain_modal.switching", "Cambiando...");
+    map.insert("chain_modal.connected", "Conectado");
 
     // Transaction status
     map.insert("transaction.pending", "Transacción pendiente");
     map.insert("transaction.confirmed", "Transacción confirmada");
     map.insert("transaction.failed", "Transacción fallida");
+    map.insert("transaction.sped_up", "Transacción acelerada");
+    map.insert("transaction.cancelled", "Transacción cancelada");
+    map.insert("transaction.replaced", "Transacción reemplazada");
     map.insert("transaction.view", "Ver transacción");
 
+    // Add token
+    map.insert("add_token.label", "Añadir a la billetera");
+    map.insert("add_token.adding", "Añadiendo...");
+    map.insert("add_token.added", "{symbol} añadido a tu billetera");
+    map.insert("add_token.failed", "No se pudo añadir el token");
+
     // Wallet names
     map.insert("wallet.metamask", "MetaMask");
     map.insert("wallet.walletconnect", "WalletConnect");
@@ -45,6 +62,12 @@ pub fn translations() -> HashMap<&'static str, &'static str> {
     map.insert("error.user_rejected", "Usuario rechazó la solicitud");
     map.insert("error.not_installed", "Billetera no instalada");
     map.insert("error.unsupported_chain", "Cadena no soportada");
+    map.insert("error.no_accounts", "No hay cuentas disponibles en la billetera");
+    map.insert("error.invalid_rpc_url", "URL de RPC no válida");
+    map.insert("error.already_connecting", "Ya hay una solicitud de conexión pendiente");
+    map.insert("error.not_connected", "Billetera no conectada");
+    map.insert("error.request_failed", "La solicitud falló");
+    map.insert("error.invalid_signature", "La firma no coincide con tu cuenta");
 
     // Common
     map.insert("common.cancel", "Cancelar");
@@ -14,6 +14,9 @@ pub fn translations() -> HashMap<&'static str, &'static str> {
     map.insert("connect_modal.get_wallet", "Je n'ai pas de portefeuille");
     map.insert("connect_modal.install_extension", "Installer l'Extension");
     map.insert("connect_modal.not_available", "Non disponible");
+    map.insert("connect_modal.opening", "Ouverture de {wallet}...");
+    map.insert("connect_modal.confirm_connection", "Confirmez la connexion dans l'extension");
+    map.insert("connect_modal.retry", "Réessayer");
 
     // Account modal
     map.insert("account_modal.title", "Compte");
@@ -21,18 +24,32 @@ pub fn translations() -> HashMap<&'static str, &'static str> {
     map.insert("account_modal.copy_address", "Copier l'Adresse");
     map.insert("account_modal.copied", "Copié !");
     map.insert("account_modal.view_explorer", "Voir sur l'Explorateur");
+    map.insert("account_modal.recent_transactions", "Transactions Récentes");
+    map.insert("account_modal.no_transactions", "Vos transactions apparaîtront ici");
+    map.insert("account_modal.clear_all", "Tout effacer");
 
     // Chain modal
     map.insert("chain_modal.title", "Changer de Réseaux");
     map.insert("chain_modal.wrong_network", "Mauvais réseau");
+    map.insert("chain_modal.wrong_network_description", "Mauvais réseau détecté, changez ou déconnectez-vous pour continuer.");
     map.insert("chain_modal.switching", "Changement...");
+    map.insert("chain_modal.connected", "Connecté");
 
     // Transaction status
     map.insert("transaction.pending", "Transaction en attente");
     map.insert("transaction.confirmed", "Transaction confirmée");
     map.insert("transaction.failed", "Transaction échouée");
+    map.insert("transaction.sped_up", "Transaction accélérée");
+    map.insert("transaction.cancelled", "Transaction annulée");
+    map.insert("transaction.replaced", "Transaction remplacée");
     map.insert("transaction.view", "Voir la transaction");
 
+    // Add token
+    map.insert("add_token.label", "Ajouter au portefeuille");
+    map.insert("add_token.adding", "Ajout...");
+    map.insert("add_token.added", "{symbol} ajouté à votre portefeuille");
+    map.insert("add_token.failed", "Impossible d'ajouter le jeton");
+
     // Wallet names
     map.insert("wallet.metamask", "MetaMask");
     map.insert("wallet.walletconnect", "WalletConnect");
@@ -45,6 +62,12 @@ pub fn translations() -> HashMap<&'static str, &'static str> {
     map.insert("error.user_rejected", "L'utilisateur a rejeté la demande");
     map.insert("error.not_installed", "Portefeuille non installé");
     map.insert("error.unsupported_chain", "Chaîne non prise en charge");
+    map.insert("error.no_accounts", "Aucun compte disponible dans le portefeuille");
+    map.insert("error.invalid_rpc_url", "URL RPC invalide");
+    map.insert("error.already_connecting", "Une demande de connexion est déjà en cours");
+    map.insert("error.not_connected", "Portefeuille non connecté");
+    map.insert("error.request_failed", "La requête a échoué");
+    map.insert("error.invalid_signature", "La signature ne correspond pas à votre compte");
 
     // Common
     map.insert("common.cancel", "Annuler");
@@ -57,10 +57,10 @@ impl Default for Locale {
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone)]
+#[derive(Clone, Copy)]
 pub struct I18n {
     locale: RwSignal<Locale>,
-    translations: HashMap<Locale, HashMap<&'static str, &'static str>>,
+    translations: StoredValue<HashMap<Locale, HashMap<&'static str, &'static str>>>,
 }
 
 impl I18n {
@@ -72,7 +72,7 @@ impl I18n {
 
         Self {
             locale: RwSignal::new(locale),
-            translations,
+            translations: StoredValue::new(translations),
         }
     }
 
@@ -80,9 +80,12 @@ impl I18n {
     pub fn t(&self, key: &str) -> String {
         let locale = self.locale.get();
         self.translations
-            .get(&locale)
-            .and_then(|t| t.get(key))
-            .map(|s| s.to_string())
+            .with_value(|translations| {
+                translations
+                    .get(&locale)
+                    .and_then(|t| t.get(key))
+                    .map(|s| s.to_string())
+            })
             .unwrap_or_else(|| {
                 log::warn!("Missing translation for key: {} in locale: {:?}", key, locale);
                 key.to_string()
@@ -108,7 +111,7 @@ impl I18n {
 /// Provide i18n in the Leptos context
 pub fn provide_i18n(locale: Locale) -> I18n {
     let i18n = I18n::new(locale);
-    provide_context(i18n.clone());
+    provide_context(i18n);
     i18n
 }
 
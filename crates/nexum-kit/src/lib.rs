@@ -25,14 +25,18 @@ pub mod wallets;
 pub mod hooks;
 pub mod utils;
 pub mod i18n;
+pub mod error;
 pub mod prelude;
 
 // Re-exports
 pub use components::{
     ConnectButton,
+    AddTokenButton,
     NexumKitProvider,
     ConnectModal,
     AccountModal,
+    ChainModal,
+    Toaster,
 };
 
 pub use theme::{
@@ -45,6 +49,13 @@ pub use hooks::{
     use_wallet,
     use_balance,
     use_ens_name,
+    use_ens_address,
+    use_ens_avatar,
+    use_toast,
+    use_send_transaction,
+    use_send_calls,
+    use_sign_message,
+    use_sign_typed_data,
 };
 
 pub use state::{
@@ -53,9 +64,16 @@ pub use state::{
     ConnectionStatus,
     TransactionStore,
     TransactionStatus,
+    ReplacementReason,
     Transaction,
+    EnsCacheConfig,
+    Toast,
+    ToastKind,
+    ToastState,
 };
 
+pub use error::{ConnectError, RequestError};
+
 pub use i18n::{
     Locale,
     use_i18n,
@@ -64,6 +82,12 @@ pub use i18n::{
 pub use provider::{
     Eip1193Transport,
     ChainConfig,
+    Call,
+    SendCallsRequest,
+    CallsStatus,
+    CallsState,
+    WalletCapabilities,
+    WatchAssetParams,
 };
 
 #[cfg(target_arch = "wasm32")]
@@ -1,11 +1,11 @@
 //! Common imports for NexumKit applications
 
-pub use crate::components::{ConnectButton, NexumKitProvider, NexumKitProviderSimple};
+pub use crate::components::{ConnectButton, AddTokenButton, NexumKitProvider, NexumKitProviderSimple, Toaster};
 pub use crate::components::primitives::{Box, Text, BoxDisplay, BoxFontWeight, BoxTextAlign, QrCode};
 pub use crate::theme::{Theme, LightTheme, DarkTheme, MidnightTheme, ThemeOptions};
-pub use crate::hooks::{use_wallet, use_balance, use_ens_name};
-pub use crate::state::{use_transaction_store, TransactionStatus, WalletProvider};
-pub use crate::provider::{Eip1193Transport, ChainConfig};
+pub use crate::hooks::{use_wallet, use_balance, use_ens_name, use_toast, use_send_transaction, use_send_calls, use_sign_message, use_sign_typed_data};
+pub use crate::state::{use_transaction_store, TransactionStatus, WalletProvider, Toast, ToastKind};
+pub use crate::provider::{Eip1193Transport, ChainConfig, Call, SendCallsRequest};
 pub use crate::i18n::{use_i18n, Locale};
 pub use leptos::prelude::*;
 pub use std::collections::HashMap;
@@ -4,8 +4,11 @@ pub mod client;
 pub use alloy_eip1193::{
     Eip1193Transport,
     Eip1193Signer,
+    Subscription,
     ChainConfig,
     ext::Eip1193,
+    ext::{Call, SendCallsRequest, CallsStatus, CallsState, WalletCapabilities},
+    ext::WatchAssetParams,
 };
 
 pub use client::create_http_provider;
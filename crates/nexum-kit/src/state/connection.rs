@@ -1,22 +1,45 @@
 use leptos::prelude::*;
 use alloy::primitives::Address;
-use alloy::providers::ProviderBuilder;
+use alloy::providers::{Provider, ProviderBuilder, RootProvider};
+use alloy::network::Ethereum;
+use alloy_eip1193::{Chain, Eip1193Error, ProviderEvent};
+use crate::wallets::connector::revoke_account_access;
 use crate::wallets::wallet::WalletConnector;
-use crate::provider::Eip1193Transport;
+use crate::error::ConnectError;
+use crate::provider::{Eip1193Transport, Eip1193Signer, Eip1193, ChainConfig, Subscription};
+use crate::utils::storage::local_storage;
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
-use web_sys::js_sys;
+use web_sys::{BroadcastChannel, MessageEvent};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+const STORAGE_KEY: &str = "nexumkit_connection";
+
+/// Channel other tabs of the app listen on for disconnects
+const BROADCAST_CHANNEL: &str = "nexumkit_connection";
+const DISCONNECT_MESSAGE: &str = "disconnect";
+
 /// Connection status enum
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConnectionStatus {
     Disconnected,
     Connecting,
+    /// Silently restoring the previous session on page load
+    Reconnecting,
     Connected,
 }
 
+/// Last connected wallet, persisted to localStorage for auto-reconnect
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersistedConnection {
+    /// ID of the connector that was used (e.g., "metamask")
+    pub connector_id: String,
+    /// EIP-6963 reverse DNS identifier of the wallet, if known
+    pub rdns: Option<String>,
+}
+
 /// Combined provider type: We use Arc<dyn Provider> to make it cloneable for Leptos signals
 /// Combines HTTP transport for RPC with EIP-1193 signer for wallet operations
 pub type WalletProvider = Arc<dyn alloy::providers::Provider>;
@@ -39,6 +62,15 @@ pub struct ConnectionState {
     pub chain_id: RwSignal<Option<u64>>,
     pub connector_id: RwSignal<Option<String>>,
     pub provider: RwSignal<Option<WalletProvider>>,
+    /// EIP-1193 transport of the connected wallet, for wallet-only requests
+    /// such as `wallet_switchEthereumChain`
+    pub wallet_transport: RwSignal<Option<Eip1193Transport>>,
+    /// Error of the last failed `connect`, cleared when a new attempt starts
+    pub last_error: RwSignal<Option<ConnectError>>,
+    /// Event listeners on the connected wallet, removed when dropped
+    subscriptions: StoredValue<Vec<Subscription>, LocalStorage>,
+    /// Receives disconnects from other tabs
+    disconnect_channel: StoredValue<Option<DisconnectChannel>, LocalStorage>,
     /// Consumer-provided RPC URLs for each chain
     pub(crate) transports: HashMap<u64, String>,
 }
@@ -51,10 +83,52 @@ impl ConnectionState {
             chain_id: RwSignal::new(None),
             connector_id: RwSignal::new(None),
             provider: RwSignal::new(None),
+            wallet_transport: RwSignal::new(None),
+            last_error: RwSignal::new(None),
+            subscriptions: StoredValue::new_local(Vec::new()),
+            disconnect_channel: StoredValue::new_local(None),
             transports,
         }
     }
 
+    /// Chain IDs with a consumer-provided RPC URL, in ascending order
+    pub fn chains(&self) -> Vec<u64> {
+        let mut chains: Vec<u64> = self.transports.keys().copied().collect();
+        chains.sort_unstable();
+        chains
+    }
+
+    /// Check if a consumer-provided RPC URL exists for a chain
+    pub fn is_supported_chain(&self, chain_id: u64) -> bool {
+        self.transports.contains_key(&chain_id)
+    }
+
+    /// Build the `wallet_addEthereumChain` configuration for a configured chain
+    ///
+    /// Uses the consumer's RPC URL and, when alloy-chains knows the network,
+    /// its Etherscan-style block explorer.
+    pub fn chain_config(&self, chain_id: u64) -> Option<ChainConfig> {
+        let rpc_url = self.transports.get(&chain_id)?;
+        let chain = Chain::from_id(chain_id);
+
+        let mut builder = ChainConfig::builder()
+            .chain(chain)
+            .rpc_url(rpc_url.clone());
+        if let Some((_, explorer_url)) = chain.etherscan_urls() {
+            builder = builder.block_explorer(explorer_url);
+        }
+
+        Some(builder.build())
+    }
+
+    /// Read-only provider for the consumer's transport on `chain_id`, e.g. for ENS
+    ///
+    /// Returns `None` if no RPC URL is configured for the chain.
+    pub fn rpc_provider(&self, chain_id: u64) -> Option<RootProvider<Ethereum>> {
+        let url = self.transports.get(&chain_id)?.parse().ok()?;
+        Some(RootProvider::new_http(url))
+    }
+
     /// Get the Alloy provider if connected
     ///
     /// Returns a fully-featured Alloy provider that combines:
@@ -70,195 +144,104 @@ impl ConnectionState {
         self.provider.get()
     }
 
+    /// Signer for the connected account, backed by the connected wallet
+    ///
+    /// Returns `None` if no wallet is connected. Not reactive.
+    pub fn signer(&self) -> Option<Eip1193Signer> {
+        let transport = self.wallet_transport.get_untracked()?;
+        let address = self.address.get_untracked()?;
+
+        Some(Eip1193Signer::from_transport(transport, address, self.chain_id.get_untracked()))
+    }
+
     /// Setup EIP-1193 event listeners for automatic state synchronization
     ///
     /// This sets up listeners for all required EIP-1193 events:
     /// - accountsChanged: Updates address when user switches account in wallet
-    /// - chainChanged: Updates chain_id when user switches network in wallet
+    /// - chainChanged: Updates chain_id and rebuilds the provider for the new chain
     /// - disconnect: Clears state when wallet disconnects
     /// - connect: Handles wallet reconnection
     ///
-    /// Note: The closures are leaked (using `forget`) because:
-    /// 1. They need to live for the lifetime of the browser session
-    /// 2. Storing them would require Send + Sync, which Closure doesn't implement
-    /// 3. In WASM, memory leaks are less critical as the page will eventually reload
-    fn setup_event_listeners(&self, ethereum: &JsValue) {
-
-        // accountsChanged listener
-        {
-            let address_signal = self.address;
-            let status_signal = self.status;
-
-            let closure = Closure::wrap(Box::new(move |accounts: JsValue| {
+    /// Returns the subscription handles; the listeners are removed from the wallet
+    /// as soon as they are dropped.
+    fn setup_event_listeners(&self, transport: &Eip1193Transport) -> Vec<Subscription> {
+        let state = self.clone();
+        let ethereum = transport.ethereum().clone();
+
+        match transport.on_events(move |event| state.handle_event(&ethereum, event)) {
+            Ok(subscriptions) => {
+                log::info!("EIP-1193 event listeners setup complete");
+                subscriptions
+            }
+            Err(e) => {
+                log::error!("Failed to add EIP-1193 event listeners: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Apply an event emitted by the connected wallet
+    fn handle_event(&self, ethereum: &JsValue, event: ProviderEvent) {
+        let current_status = self.status.get_untracked();
+
+        match event {
+            ProviderEvent::AccountsChanged(accounts) => {
                 // Guard: Only process if currently connected
-                // This prevents the leaked closure from affecting disconnected state
-                let current_status = status_signal.get_untracked();
                 if current_status == ConnectionStatus::Disconnected {
                     log::debug!("accountsChanged event ignored - wallet is disconnected");
                     return;
                 }
 
-                log::debug!("accountsChanged event fired");
-
-                if let Ok(accounts_array) = accounts.dyn_into::<js_sys::Array>() {
-                    if accounts_array.length() > 0 {
-                        if let Some(account_str) = accounts_array.get(0).as_string() {
-                            match account_str.parse::<Address>() {
-                                Ok(address) => {
-                                    log::info!("Account changed to: {:?}", address);
-                                    address_signal.set(Some(address));
-                                }
-                                Err(e) => log::error!("Failed to parse address: {}", e),
-                            }
-                        }
-                    } else {
+                match accounts.first() {
+                    Some(address) => {
+                        log::info!("Account changed to: {:?}", address);
+                        self.address.set(Some(*address));
+                    }
+                    None => {
                         // Empty array = disconnected
                         log::info!("Accounts array empty, wallet disconnected");
-                        address_signal.set(None);
-                        status_signal.set(ConnectionStatus::Disconnected);
+                        self.clear_connection_from_event();
                     }
                 }
-            }) as Box<dyn FnMut(JsValue)>);
-
-            if let Ok(on_fn) = js_sys::Reflect::get(ethereum, &"on".into()) {
-                if let Ok(on_fn) = on_fn.dyn_into::<js_sys::Function>() {
-                    let _ = on_fn.call2(
-                        ethereum,
-                        &"accountsChanged".into(),
-                        closure.as_ref().unchecked_ref(),
-                    );
-                }
             }
-
-            // Leak the closure so it lives for the lifetime of the page
-            closure.forget();
-        }
-
-        // chainChanged listener
-        {
-            let chain_id_signal = self.chain_id;
-            let status_signal = self.status;
-
-            let closure = Closure::wrap(Box::new(move |chain_id_hex: JsValue| {
+            ProviderEvent::ChainChanged(chain_id) => {
                 // Guard: Only process if currently connected
-                // This prevents the leaked closure from affecting disconnected state
-                let current_status = status_signal.get_untracked();
                 if current_status == ConnectionStatus::Disconnected {
                     log::debug!("chainChanged event ignored - wallet is disconnected");
                     return;
                 }
 
-                log::debug!("chainChanged event fired");
-
-                if let Some(chain_id_str) = chain_id_hex.as_string() {
-                    // Parse hex chain ID (e.g., "0x1" -> 1)
-                    let chain_id_str = chain_id_str.trim_start_matches("0x");
-                    match u64::from_str_radix(chain_id_str, 16) {
-                        Ok(chain_id) => {
-                            log::info!("Chain changed to: {}", chain_id);
-                            chain_id_signal.set(Some(chain_id));
-                        }
-                        Err(e) => log::error!("Failed to parse chain ID: {}", e),
-                    }
-                }
-            }) as Box<dyn FnMut(JsValue)>);
-
-            if let Ok(on_fn) = js_sys::Reflect::get(ethereum, &"on".into()) {
-                if let Ok(on_fn) = on_fn.dyn_into::<js_sys::Function>() {
-                    let _ = on_fn.call2(
-                        ethereum,
-                        &"chainChanged".into(),
-                        closure.as_ref().unchecked_ref(),
-                    );
-                }
+                log::info!("Chain changed to: {}", chain_id);
+                self.set_chain(ethereum, chain_id);
             }
-
-            // Leak the closure so it lives for the lifetime of the page
-            closure.forget();
-        }
-
-        // disconnect listener
-        {
-            let status_signal = self.status;
-            let address_signal = self.address;
-            let chain_id_signal = self.chain_id;
-            let provider_signal = self.provider;
-            let connector_id_signal = self.connector_id;
-
-            let closure = Closure::wrap(Box::new(move |_error: JsValue| {
+            ProviderEvent::Disconnect(error) => {
                 // Guard: Only process if not already disconnected
                 // This prevents duplicate disconnection handling
-                let current_status = status_signal.get_untracked();
                 if current_status == ConnectionStatus::Disconnected {
                     log::debug!("disconnect event ignored - already disconnected");
                     return;
                 }
 
-                log::info!("Wallet disconnect event - clearing connection state");
-                status_signal.set(ConnectionStatus::Disconnected);
-                address_signal.set(None);
-                chain_id_signal.set(None);
-                provider_signal.set(None);
-                connector_id_signal.set(None);
-            }) as Box<dyn FnMut(JsValue)>);
-
-            if let Ok(on_fn) = js_sys::Reflect::get(ethereum, &"on".into()) {
-                if let Ok(on_fn) = on_fn.dyn_into::<js_sys::Function>() {
-                    let _ = on_fn.call2(
-                        ethereum,
-                        &"disconnect".into(),
-                        closure.as_ref().unchecked_ref(),
-                    );
-                }
+                log::info!("Wallet disconnect event ({}) - clearing connection state", error);
+                self.clear_connection_from_event();
             }
-
-            // Leak the closure so it lives for the lifetime of the page
-            closure.forget();
-        }
-
-        // connect listener
-        {
-            let status_signal = self.status;
-            let chain_id_signal = self.chain_id;
-
-            let closure = Closure::wrap(Box::new(move |connect_info: JsValue| {
+            ProviderEvent::Connect { chain_id } => {
                 // Guard: Only process if we're in a connecting or disconnected state
                 // This prevents spurious connect events when already connected
-                let current_status = status_signal.get_untracked();
                 if current_status == ConnectionStatus::Connected {
                     log::debug!("connect event ignored - already connected");
                     return;
                 }
 
-                log::info!("Wallet connect event received");
-                if let Ok(chain_id_hex) = js_sys::Reflect::get(&connect_info, &"chainId".into()) {
-                    if let Some(chain_id_str) = chain_id_hex.as_string() {
-                        let chain_id_str = chain_id_str.trim_start_matches("0x");
-                        if let Ok(chain_id) = u64::from_str_radix(chain_id_str, 16) {
-                            log::debug!("Setting chain ID from connect event: {}", chain_id);
-                            chain_id_signal.set(Some(chain_id));
-                        }
-                    }
-                }
                 // Note: We don't set status to Connected here, as the full connection
                 // flow handles this. This event is primarily for chain ID updates.
-            }) as Box<dyn FnMut(JsValue)>);
-
-            if let Ok(on_fn) = js_sys::Reflect::get(ethereum, &"on".into()) {
-                if let Ok(on_fn) = on_fn.dyn_into::<js_sys::Function>() {
-                    let _ = on_fn.call2(
-                        ethereum,
-                        &"connect".into(),
-                        closure.as_ref().unchecked_ref(),
-                    );
-                }
+                log::debug!("Setting chain ID from connect event: {}", chain_id);
+                self.chain_id.set(Some(chain_id));
+            }
+            ProviderEvent::Message { r#type, .. } => {
+                log::debug!("Ignoring wallet message: {}", r#type);
             }
-
-            // Leak the closure so it lives for the lifetime of the page
-            closure.forget();
         }
-
-        log::info!("EIP-1193 event listeners setup complete");
     }
 
     /// Connect to a wallet
@@ -267,16 +250,17 @@ impl ConnectionState {
     /// 1. Checks if already connecting to prevent duplicate requests
     /// 2. Sets status to Connecting
     /// 3. Calls the connector's connect() method
-    /// 4. Creates HTTP provider with consumer's RPC URL
-    /// 5. Creates EIP-1193 signer from wallet
-    /// 6. Combines them into a provider
-    /// 7. Sets up EIP-1193 event listeners for auto-sync
-    /// 8. Updates all state signals on success
-    pub async fn connect<C: WalletConnector>(&self, connector: &C) -> Result<(), JsValue> {
+    /// 4. Creates HTTP provider with consumer's RPC URL (if the chain is configured)
+    /// 5. Layers EIP-1193 wallet routing on top of it
+    /// 6. Sets up EIP-1193 event listeners for auto-sync
+    /// 7. Updates all state signals on success
+    ///
+    /// On failure the error is also stored in `last_error`.
+    pub async fn connect<C: WalletConnector>(&self, connector: &C) -> Result<(), ConnectError> {
         // Prevent duplicate connection attempts
-        if self.status.get_untracked() == ConnectionStatus::Connecting {
+        if matches!(self.status.get_untracked(), ConnectionStatus::Connecting | ConnectionStatus::Reconnecting) {
             log::warn!("Connection already in progress, ignoring duplicate request");
-            return Err(JsValue::from_str("Connection already in progress"));
+            return Err(ConnectError::AlreadyConnecting);
         }
 
         // Already connected to this wallet
@@ -287,109 +271,269 @@ impl ConnectionState {
         }
 
         log::info!("Connecting to wallet: {}", connector.metadata().name);
+        self.last_error.set(None);
         self.status.set(ConnectionStatus::Connecting);
 
-        match connector.connect().await {
+        let result = match connector.connect().await {
             Ok(address) => {
                 log::info!("Successfully connected: {:?}", address);
+                self.complete_connection(connector, address).await
+            }
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = &result {
+            log::error!("Failed to connect: {:?}", e);
+            self.subscriptions.set_value(Vec::new());
+            self.status.set(ConnectionStatus::Disconnected);
+            self.provider.set(None);
+            self.last_error.set(Some(e.clone()));
+        }
 
-                // Get the ethereum provider from the connector
-                let ethereum_js = connector.get_provider()
-                    .ok_or_else(|| JsValue::from_str("Connector did not provide ethereum provider"))?;
-
-                // Get current chain ID from wallet
-                let transport = Eip1193Transport::new(ethereum_js.clone());
-                let chain_id = self.get_current_chain_id(&transport).await?;
-
-                // Get consumer's RPC URL for this chain
-                let rpc_url = self.transports.get(&chain_id)
-                    .ok_or_else(|| JsValue::from_str(&format!("No RPC URL configured for chain {}", chain_id)))?
-                    .clone();
-
-                log::info!("Using RPC URL: {} for chain {}", rpc_url, chain_id);
-
-                // Create WalletLayer to route wallet operations through EIP-1193
-                let wallet_layer = alloy_eip1193::WalletLayer::new(ethereum_js.clone());
-
-                // Create provider with WalletLayer + HTTP transport
-                // This routes wallet operations (eth_sendTransaction) to browser wallet
-                // while RPC reads go to the HTTP provider
-                let url: reqwest::Url = rpc_url.parse().map_err(|e| JsValue::from_str(&format!("Invalid RPC URL: {}", e)))?;
-                let provider = ProviderBuilder::new()
-                    .layer(wallet_layer)
-                    .on_http(url);
-
-                // Wrap in Arc to make it cloneable for Leptos signals
-                let provider: WalletProvider = Arc::new(provider);
+        result
+    }
 
-                // Setup EIP-1193 event listeners for automatic state sync
-                self.setup_event_listeners(&ethereum_js);
+    /// Silently restore a previous connection
+    ///
+    /// Uses the connector's `reconnect()`, which reads already-authorized accounts via
+    /// `eth_accounts`, so the wallet never shows a popup. If the wallet no longer
+    /// exposes any account, the persisted connector is forgotten.
+    ///
+    /// Returns whether the connection was restored.
+    pub async fn reconnect<C: WalletConnector>(&self, connector: &C) -> Result<bool, ConnectError> {
+        if matches!(self.status.get_untracked(), ConnectionStatus::Connecting | ConnectionStatus::Connected) {
+            log::debug!("Skipping reconnect, connection already in progress or established");
+            return Ok(false);
+        }
 
-                // Update all state
-                self.address.set(Some(address));
-                self.chain_id.set(Some(chain_id));
-                self.connector_id.set(Some(connector.metadata().id.clone()));
-                self.provider.set(Some(provider));
-                self.status.set(ConnectionStatus::Connected);
+        log::info!("Reconnecting to wallet: {}", connector.metadata().name);
+        self.status.set(ConnectionStatus::Reconnecting);
 
-                log::info!("Connection successful, provider created with WalletLayer + HTTP transport");
-                Ok(())
+        let result = match connector.reconnect().await {
+            Ok(Some(address)) => self.complete_connection(connector, address).await.map(|_| true),
+            Ok(None) => {
+                log::info!("{} has no authorized accounts, not reconnecting", connector.metadata().name);
+                clear_persisted_connection();
+                Ok(false)
             }
+            Err(e) => Err(e),
+        };
+
+        match &result {
+            Ok(true) => log::info!("Reconnected to {}", connector.metadata().name),
+            Ok(false) => self.status.set(ConnectionStatus::Disconnected),
             Err(e) => {
-                log::error!("Failed to connect: {:?}", e);
+                log::warn!("Failed to reconnect: {:?}", e);
                 self.status.set(ConnectionStatus::Disconnected);
                 self.provider.set(None);
-                Err(e)
             }
         }
+
+        result
+    }
+
+    /// Finish a connection once the connector has returned an account
+    ///
+    /// Builds the provider for the wallet's current chain, sets up event listeners,
+    /// persists the connector for auto-reconnect and updates all state signals.
+    async fn complete_connection<C: WalletConnector>(&self, connector: &C, address: Address) -> Result<(), ConnectError> {
+        // Get the ethereum provider from the connector
+        let ethereum_js = connector.get_provider()
+            .ok_or_else(|| ConnectError::NotInstalled(connector.metadata().name.clone()))?;
+
+        // Get current chain ID from wallet
+        let transport = Eip1193Transport::new(ethereum_js.clone());
+        let chain_id = self.get_current_chain_id(&transport).await?;
+
+        // A chain without an RPC URL doesn't fail the connection: the wallet is
+        // connected on the wrong network and no provider is available until the
+        // user switches to one of the configured chains.
+        let provider = self.build_provider(&ethereum_js, chain_id)?;
+
+        // Setup EIP-1193 event listeners for automatic state sync. Replacing the
+        // previous handles removes the listeners of any previously connected wallet.
+        self.subscriptions.set_value(self.setup_event_listeners(&transport));
+
+        // Remember this wallet so the next page load can reconnect silently
+        persist_connection(&PersistedConnection {
+            connector_id: connector.metadata().id.clone(),
+            rdns: connector.metadata().rdns.clone(),
+        });
+
+        // Update all state
+        self.address.set(Some(address));
+        self.chain_id.set(Some(chain_id));
+        self.connector_id.set(Some(connector.metadata().id.clone()));
+        self.provider.set(provider);
+        self.wallet_transport.set(Some(transport));
+        self.status.set(ConnectionStatus::Connected);
+
+        log::info!("Connection successful");
+        Ok(())
+    }
+
+    /// Build the WalletLayer + HTTP provider for a chain
+    ///
+    /// Returns `Ok(None)` if no RPC URL is configured for the chain.
+    fn build_provider(&self, ethereum: &JsValue, chain_id: u64) -> Result<Option<WalletProvider>, ConnectError> {
+        // Get consumer's RPC URL for this chain
+        let Some(rpc_url) = self.transports.get(&chain_id) else {
+            log::warn!("No RPC URL configured for chain {}, wallet is on the wrong network", chain_id);
+            return Ok(None);
+        };
+
+        log::info!("Using RPC URL: {} for chain {}", rpc_url, chain_id);
+
+        // Create WalletLayer to route wallet operations through EIP-1193
+        let wallet_layer = alloy_eip1193::WalletLayer::new(ethereum.clone());
+
+        // Create provider with WalletLayer + HTTP transport
+        // This routes wallet methods (accounts, signing, eth_sendTransaction, wallet_*)
+        // to the browser wallet while RPC reads go to the HTTP provider
+        let url: reqwest::Url = rpc_url.parse()
+            .map_err(|e: url::ParseError| ConnectError::InvalidRpcUrl { chain_id, reason: e.to_string() })?;
+        let provider = ProviderBuilder::new()
+            .layer(wallet_layer)
+            .connect_http(url);
+
+        // Wrap in Arc to make it cloneable for Leptos signals
+        Ok(Some(Arc::new(provider)))
+    }
+
+    /// Move the connection to a new chain
+    ///
+    /// Rebuilds the provider for the new chain's transport (or clears it if the chain
+    /// isn't configured) before updating `chain_id`, so anything reacting to `chain_id`
+    /// never sees a provider pointing at the previous chain.
+    fn set_chain(&self, ethereum: &JsValue, chain_id: u64) {
+        let provider = self.build_provider(ethereum, chain_id).unwrap_or_else(|e| {
+            log::error!("Failed to build provider for chain {}: {:?}", chain_id, e);
+            None
+        });
+
+        self.provider.set(provider);
+        self.chain_id.set(Some(chain_id));
     }
 
     /// Get current chain ID from wallet
-    async fn get_current_chain_id(&self, transport: &Eip1193Transport) -> Result<u64, JsValue> {
-        let request_obj = js_sys::Object::new();
-        js_sys::Reflect::set(&request_obj, &"method".into(), &"eth_chainId".into())
-            .map_err(|e| JsValue::from_str(&format!("Failed to create request: {:?}", e)))?;
-        js_sys::Reflect::set(&request_obj, &"params".into(), &js_sys::Array::new())
-            .map_err(|e| JsValue::from_str(&format!("Failed to set params: {:?}", e)))?;
-
-        let request_fn = js_sys::Reflect::get(transport.ethereum(), &"request".into())
-            .map_err(|e| JsValue::from_str(&format!("Failed to get request fn: {:?}", e)))?;
-        let request_fn = request_fn
-            .dyn_into::<js_sys::Function>()
-            .map_err(|e| JsValue::from_str(&format!("Request is not a function: {:?}", e)))?;
-
-        let promise = request_fn
-            .call1(transport.ethereum(), &request_obj)
-            .map_err(|e| JsValue::from_str(&format!("Failed to call request: {:?}", e)))?;
-        let promise = promise
-            .dyn_into::<js_sys::Promise>()
-            .map_err(|e| JsValue::from_str(&format!("Not a promise: {:?}", e)))?;
-
-        let result = wasm_bindgen_futures::JsFuture::from(promise).await
-            .map_err(|e| JsValue::from_str(&format!("Request failed: {:?}", e)))?;
-
-        let chain_id_hex = result.as_string()
-            .ok_or_else(|| JsValue::from_str("Chain ID is not a string"))?;
-
-        let chain_id_hex = chain_id_hex.trim_start_matches("0x");
-        u64::from_str_radix(chain_id_hex, 16)
-            .map_err(|e| JsValue::from_str(&format!("Failed to parse chain ID: {}", e)))
+    async fn get_current_chain_id(&self, transport: &Eip1193Transport) -> Result<u64, ConnectError> {
+        let provider = RootProvider::<Ethereum>::new(transport.clone().into_client());
+
+        Ok(provider.get_chain_id().await?)
+    }
+
+    /// Get the last connected wallet persisted in localStorage, if any
+    pub fn last_connection(&self) -> Option<PersistedConnection> {
+        load_persisted_connection()
     }
 
     /// Disconnect from the wallet
-    pub async fn disconnect(&self) -> Result<(), JsValue> {
+    ///
+    /// Also forgets the persisted connector, so the next page load won't reconnect,
+    /// and revokes the site's `eth_accounts` permission if the wallet supports
+    /// `wallet_revokePermissions`. Failing to revoke it doesn't keep the app connected.
+    pub async fn disconnect(&self) -> Result<(), ConnectError> {
         log::info!("Disconnecting wallet");
+        let transport = self.wallet_transport.get_untracked();
+        clear_persisted_connection();
+        self.clear_connection();
+
+        // Log out the app's other tabs too
+        self.disconnect_channel.with_value(|channel| {
+            if let Some(channel) = channel {
+                let _ = channel.channel.post_message(&JsValue::from_str(DISCONNECT_MESSAGE));
+            }
+        });
+
+        if let Some(transport) = transport {
+            match revoke_account_access(transport).await {
+                Ok(true) => log::debug!("Revoked account access"),
+                Ok(false) => log::debug!("Wallet can't revoke account access"),
+                Err(e) => log::warn!("Failed to revoke account access: {}", e),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reset the connection in this tab only
+    fn clear_connection(&self) {
+        // Remove the event listeners from the wallet
+        self.subscriptions.set_value(Vec::new());
+        self.reset_state();
+    }
 
-        // Note: Event listeners are leaked and will remain active.
-        // This is intentional as they handle wallet-side disconnections.
+    /// Forget the connection after the wallet disconnected, from inside one of its listeners
+    fn clear_connection_from_event(&self) {
+        clear_persisted_connection();
+        release_subscriptions(self.subscriptions);
+        self.reset_state();
+    }
 
-        // Clear all state
+    /// Clear all connection state, leaving the wallet's event listeners to the caller
+    fn reset_state(&self) {
         self.address.set(None);
         self.chain_id.set(None);
         self.connector_id.set(None);
         self.provider.set(None);
+        self.wallet_transport.set(None);
         self.status.set(ConnectionStatus::Disconnected);
-        Ok(())
+    }
+
+    /// Disconnect this tab when the user disconnects in another tab
+    ///
+    /// Does nothing in browsers without `BroadcastChannel`.
+    pub fn sync_disconnects_across_tabs(&self) {
+        let channel = match BroadcastChannel::new(BROADCAST_CHANNEL) {
+            Ok(channel) => channel,
+            Err(e) => {
+                log::warn!("BroadcastChannel unavailable, disconnects won't sync across tabs: {:?}", e);
+                return;
+            }
+        };
+
+        let state = self.clone();
+        let on_message = Closure::wrap(Box::new(move |event: MessageEvent| {
+            if event.data().as_string().as_deref() != Some(DISCONNECT_MESSAGE) {
+                return;
+            }
+            if state.status.get_untracked() != ConnectionStatus::Disconnected {
+                log::info!("Disconnected in another tab");
+                state.clear_connection();
+            }
+        }) as Box<dyn FnMut(MessageEvent)>);
+        channel.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+
+        self.disconnect_channel.set_value(Some(DisconnectChannel { channel, _on_message: on_message }));
+    }
+
+    /// Switch the connected wallet to another chain
+    ///
+    /// Sends `wallet_switchEthereumChain`. If the wallet doesn't know the chain
+    /// (EIP-1193 error 4902), falls back to `wallet_addEthereumChain` using the
+    /// consumer-provided RPC URL for that chain. The `chainChanged` event updates
+    /// `chain_id` once the wallet has switched.
+    pub async fn switch_chain(&self, chain_id: u64) -> Result<(), ConnectError> {
+        let transport = self.wallet_transport.get_untracked()
+            .ok_or(ConnectError::NotConnected)?;
+        let provider = RootProvider::<Ethereum>::new(transport.into_client());
+
+        log::info!("Switching to chain {}", chain_id);
+
+        match provider.switch_chain(chain_id).await {
+            Ok(()) => Ok(()),
+            Err(e) => match Eip1193Error::from_transport_error(&e) {
+                Some(Eip1193Error::UnrecognizedChain(_)) => {
+                    log::info!("Chain {} not added to wallet, requesting wallet_addEthereumChain", chain_id);
+
+                    let config = self.chain_config(chain_id)
+                        .ok_or(ConnectError::MissingTransport(chain_id))?;
+
+                    Ok(provider.add_chain(config).await?)
+                }
+                _ => Err(e.into()),
+            },
+        }
     }
 
     /// Check if currently connected
@@ -401,6 +545,63 @@ impl ConnectionState {
     pub fn is_connecting(&self) -> bool {
         matches!(self.status.get(), ConnectionStatus::Connecting)
     }
+
+    /// Check if silently restoring a previous session
+    pub fn is_reconnecting(&self) -> bool {
+        matches!(self.status.get(), ConnectionStatus::Reconnecting)
+    }
+
+    /// Check if connected to a chain without a consumer-provided RPC URL
+    pub fn is_wrong_network(&self) -> bool {
+        self.status.get() == ConnectionStatus::Connected
+            && self.chain_id.get().is_some_and(|chain_id| !self.is_supported_chain(chain_id))
+    }
+}
+
+/// `BroadcastChannel` for disconnects, closed when dropped
+struct DisconnectChannel {
+    channel: BroadcastChannel,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+}
+
+impl Drop for DisconnectChannel {
+    fn drop(&mut self) {
+        self.channel.set_onmessage(None);
+        self.channel.close();
+    }
+}
+
+/// Remove the wallet's event listeners from inside one of those listeners
+///
+/// A listener can't be dropped while it is running, so this is deferred until
+/// the current event has been handled.
+fn release_subscriptions(subscriptions: StoredValue<Vec<Subscription>, LocalStorage>) {
+    wasm_bindgen_futures::spawn_local(async move {
+        subscriptions.set_value(Vec::new());
+    });
+}
+
+/// Load the last connected wallet from localStorage
+fn load_persisted_connection() -> Option<PersistedConnection> {
+    let json_str = local_storage()?.get_item(STORAGE_KEY).ok()??;
+
+    serde_json::from_str(&json_str).ok()
+}
+
+/// Save the connected wallet to localStorage
+fn persist_connection(connection: &PersistedConnection) {
+    let (Some(storage), Ok(json_str)) = (local_storage(), serde_json::to_string(connection)) else {
+        return;
+    };
+
+    let _ = storage.set_item(STORAGE_KEY, &json_str);
+}
+
+/// Remove the persisted wallet from localStorage
+fn clear_persisted_connection() {
+    if let Some(storage) = local_storage() {
+        let _ = storage.remove_item(STORAGE_KEY);
+    }
 }
 
 /// Provide connection state to the component tree
@@ -412,6 +613,7 @@ impl ConnectionState {
 /// * `transports` - Map of chain_id to RPC URL for blockchain communication
 pub fn provide_connection_state(transports: HashMap<u64, String>) -> ConnectionState {
     let state = ConnectionState::new(transports);
+    state.sync_disconnects_across_tabs();
     provide_context(state.clone());
     state
 }
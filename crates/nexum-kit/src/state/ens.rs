@@ -0,0 +1,402 @@
+//! Shared cache for ENS lookups
+//!
+//! Every `use_ens_*` hook reads from this cache instead of calling the RPC itself.
+//! Lookups requested in the same tick are queued and resolved together through
+//! Multicall3 once the current task yields, so a list of addresses costs a few
+//! `eth_call`s in total.
+
+use leptos::prelude::*;
+use alloy::primitives::Address;
+use alloy::providers::RootProvider;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::time::Duration;
+use crate::state::connection::{use_connection_state, ConnectionState};
+use crate::utils::ens::{avatar_record_batch, lookup_name_batch, normalize_name, resolve_address_batch, resolve_avatar_record};
+use crate::utils::storage::local_storage;
+
+const STORAGE_KEY: &str = "nexumkit_ens_cache";
+
+/// ENS cache settings
+#[derive(Debug, Clone)]
+pub struct EnsCacheConfig {
+    /// How long a resolved name, address or avatar is reused before it's looked up again
+    pub ttl: Duration,
+    /// Keep the cache in localStorage so lookups survive a page reload
+    pub persist: bool,
+}
+
+impl Default for EnsCacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(10 * 60),
+            persist: false,
+        }
+    }
+}
+
+/// Cached lookup result, including negative results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry<T> {
+    value: T,
+    /// Unix time in milliseconds
+    expires_at: u64,
+}
+
+impl<T> CacheEntry<T> {
+    fn new(value: T, ttl: Duration) -> Self {
+        Self {
+            value,
+            expires_at: now().saturating_add(ttl.as_millis() as u64),
+        }
+    }
+
+    fn is_fresh(&self) -> bool {
+        now() < self.expires_at
+    }
+}
+
+type NameKey = (u64, Address);
+type AddressKey = (u64, String);
+
+/// Lookups waiting for, or part of, a batch
+#[derive(Debug, Clone, Default)]
+struct Lookups {
+    names: HashSet<NameKey>,
+    addresses: HashSet<AddressKey>,
+    avatars: HashSet<NameKey>,
+}
+
+impl Lookups {
+    fn is_empty(&self) -> bool {
+        self.names.is_empty() && self.addresses.is_empty() && self.avatars.is_empty()
+    }
+
+    fn contains(&self, other: &Lookups) -> bool {
+        other.names.is_subset(&self.names)
+            && other.addresses.is_subset(&self.addresses)
+            && other.avatars.is_subset(&self.avatars)
+    }
+
+    fn extend(&mut self, other: &Lookups) {
+        self.names.extend(other.names.iter().cloned());
+        self.addresses.extend(other.addresses.iter().cloned());
+        self.avatars.extend(other.avatars.iter().cloned());
+    }
+
+    fn remove(&mut self, other: &Lookups) {
+        self.names.retain(|key| !other.names.contains(key));
+        self.addresses.retain(|key| !other.addresses.contains(key));
+        self.avatars.retain(|key| !other.avatars.contains(key));
+    }
+
+    fn chains(&self) -> HashSet<u64> {
+        self.names.iter().map(|(chain_id, _)| *chain_id)
+            .chain(self.addresses.iter().map(|(chain_id, _)| *chain_id))
+            .chain(self.avatars.iter().map(|(chain_id, _)| *chain_id))
+            .collect()
+    }
+}
+
+/// Cache contents as stored in localStorage
+///
+/// JSON object keys must be strings, so the maps are stored as lists of pairs.
+#[derive(Default, Serialize, Deserialize)]
+struct PersistedEnsCache {
+    names: Vec<(NameKey, CacheEntry<Option<String>>)>,
+    addresses: Vec<(AddressKey, CacheEntry<Option<Address>>)>,
+    avatars: Vec<(NameKey, CacheEntry<Option<String>>)>,
+}
+
+/// ENS lookups keyed by (chain, address) for names and avatars, and by
+/// (chain, normalized name) for addresses
+#[derive(Clone, Copy)]
+pub struct EnsCache {
+    config: StoredValue<EnsCacheConfig>,
+    names: RwSignal<HashMap<NameKey, CacheEntry<Option<String>>>>,
+    addresses: RwSignal<HashMap<AddressKey, CacheEntry<Option<Address>>>>,
+    avatars: RwSignal<HashMap<NameKey, CacheEntry<Option<String>>>>,
+    queued: StoredValue<Lookups>,
+    in_flight: StoredValue<Lookups>,
+    connection: StoredValue<ConnectionState>,
+}
+
+impl EnsCache {
+    pub fn new(config: EnsCacheConfig, connection: ConnectionState) -> Self {
+        let persisted = if config.persist {
+            load_from_storage().unwrap_or_default()
+        } else {
+            PersistedEnsCache::default()
+        };
+
+        Self {
+            config: StoredValue::new(config),
+            names: RwSignal::new(fresh_entries(persisted.names)),
+            addresses: RwSignal::new(fresh_entries(persisted.addresses)),
+            avatars: RwSignal::new(fresh_entries(persisted.avatars)),
+            queued: StoredValue::new(Lookups::default()),
+            in_flight: StoredValue::new(Lookups::default()),
+            connection: StoredValue::new(connection),
+        }
+    }
+
+    /// Cached primary name of `address` on `chain_id`
+    ///
+    /// Reactive; an expired name is still returned until it has been refreshed.
+    pub fn name(&self, chain_id: u64, address: Address) -> Option<String> {
+        self.names.with(|names| names.get(&(chain_id, address))?.value.clone())
+    }
+
+    /// Cached address `name` resolves to on `chain_id`
+    pub fn address(&self, chain_id: u64, name: &str) -> Option<Address> {
+        let key = (chain_id, normalize_name(name)?);
+        self.addresses.with(|addresses| addresses.get(&key)?.value)
+    }
+
+    /// Cached avatar URL of the primary name of `address` on `chain_id`
+    pub fn avatar(&self, chain_id: u64, address: Address) -> Option<String> {
+        self.avatars.with(|avatars| avatars.get(&(chain_id, address))?.value.clone())
+    }
+
+    /// Queue a reverse lookup unless a fresh name is cached
+    pub fn request_name(&self, chain_id: u64, address: Address) {
+        let key = (chain_id, address);
+        if !is_fresh(self.names, &key) {
+            self.enqueue(Lookups { names: HashSet::from([key]), ..Default::default() });
+        }
+    }
+
+    /// Queue a forward lookup unless a fresh address is cached
+    ///
+    /// Names that fail ENSIP-15 normalization are never looked up.
+    pub fn request_address(&self, chain_id: u64, name: &str) {
+        let Some(name) = normalize_name(name) else {
+            return;
+        };
+
+        let key = (chain_id, name);
+        if !is_fresh(self.addresses, &key) {
+            self.enqueue(Lookups { addresses: HashSet::from([key]), ..Default::default() });
+        }
+    }
+
+    /// Queue an avatar lookup unless a fresh avatar is cached
+    pub fn request_avatar(&self, chain_id: u64, address: Address) {
+        let key = (chain_id, address);
+        if !is_fresh(self.avatars, &key) {
+            self.enqueue(Lookups { avatars: HashSet::from([key]), ..Default::default() });
+        }
+    }
+
+    /// Drop all cached lookups, including the persisted copy
+    pub fn clear(&self) {
+        self.names.set(HashMap::new());
+        self.addresses.set(HashMap::new());
+        self.avatars.set(HashMap::new());
+
+        if let Some(storage) = local_storage() {
+            let _ = storage.remove_item(STORAGE_KEY);
+        }
+    }
+
+    /// Add lookups to the queue, scheduling a flush if the queue was empty
+    fn enqueue(&self, lookups: Lookups) {
+        if self.in_flight.with_value(|in_flight| in_flight.contains(&lookups)) {
+            return;
+        }
+
+        let should_schedule = self.queued
+            .try_update_value(|queued| {
+                let was_empty = queued.is_empty();
+                queued.extend(&lookups);
+                was_empty
+            })
+            .unwrap_or(false);
+
+        if should_schedule {
+            let cache = *self;
+            wasm_bindgen_futures::spawn_local(async move {
+                cache.flush().await;
+            });
+        }
+    }
+
+    /// Resolve everything queued so far
+    async fn flush(self) {
+        let Some(lookups) = self.queued.try_update_value(std::mem::take) else {
+            return;
+        };
+        self.in_flight.update_value(|in_flight| in_flight.extend(&lookups));
+
+        for chain_id in lookups.chains() {
+            match self.connection.with_value(|connection| connection.rpc_provider(chain_id)) {
+                Some(provider) => self.resolve(&provider, chain_id, &lookups).await,
+                None => log::debug!("No transport for chain {}, skipping ENS lookups", chain_id),
+            }
+        }
+
+        self.in_flight.update_value(|in_flight| in_flight.remove(&lookups));
+        self.save_to_storage();
+    }
+
+    /// Resolve the lookups for one chain and store the results
+    async fn resolve(&self, provider: &RootProvider, chain_id: u64, lookups: &Lookups) {
+        let ttl = self.config.with_value(|config| config.ttl);
+
+        // Avatars hang off the primary name, so those addresses need a name too
+        let addresses: Vec<Address> = lookups.names.iter()
+            .chain(&lookups.avatars)
+            .filter(|(chain, _)| *chain == chain_id)
+            .map(|(_, address)| *address)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        match lookup_name_batch(provider, &addresses).await {
+            Ok(names) => {
+                self.names.update(|cache| {
+                    for (address, name) in addresses.iter().zip(&names) {
+                        cache.insert((chain_id, *address), CacheEntry::new(name.clone(), ttl));
+                    }
+                });
+
+                let avatar_owners: Vec<(Address, String)> = addresses.iter()
+                    .zip(names)
+                    .filter(|(address, _)| lookups.avatars.contains(&(chain_id, **address)))
+                    .filter_map(|(address, name)| Some((*address, name?)))
+                    .collect();
+                self.resolve_avatars(provider, chain_id, lookups, avatar_owners).await;
+            }
+            Err(e) => log::warn!("ENS name lookup failed: {}", e),
+        }
+
+        let names: Vec<String> = lookups.addresses.iter()
+            .filter(|(chain, _)| *chain == chain_id)
+            .map(|(_, name)| name.clone())
+            .collect();
+
+        match resolve_address_batch(provider, &names).await {
+            Ok(addresses) => {
+                self.addresses.update(|cache| {
+                    for (name, address) in names.into_iter().zip(addresses) {
+                        cache.insert((chain_id, name), CacheEntry::new(address, ttl));
+                    }
+                });
+            }
+            Err(e) => log::warn!("ENS address lookup failed: {}", e),
+        }
+    }
+
+    /// Resolve avatars for addresses with a verified primary name
+    ///
+    /// Requested addresses without a name get a cached `None`.
+    async fn resolve_avatars(
+        &self,
+        provider: &RootProvider,
+        chain_id: u64,
+        lookups: &Lookups,
+        owners: Vec<(Address, String)>,
+    ) {
+        let ttl = self.config.with_value(|config| config.ttl);
+        let names: Vec<String> = owners.iter().map(|(_, name)| name.clone()).collect();
+
+        let records = match avatar_record_batch(provider, &names).await {
+            Ok(records) => records,
+            Err(e) => {
+                log::warn!("ENS avatar lookup failed: {}", e);
+                return;
+            }
+        };
+
+        let mut avatars: HashMap<Address, Option<String>> = lookups.avatars.iter()
+            .filter(|(chain, _)| *chain == chain_id)
+            .map(|(_, address)| (*address, None))
+            .collect();
+
+        // NFT avatars need their own calls and a metadata fetch each
+        for ((address, name), record) in owners.into_iter().zip(records) {
+            let Some(record) = record else {
+                continue;
+            };
+
+            match resolve_avatar_record(provider, &record, address).await {
+                Ok(url) => {
+                    avatars.insert(address, url);
+                }
+                Err(e) => {
+                    log::warn!("Failed to resolve avatar of {}: {}", name, e);
+                    avatars.remove(&address);
+                }
+            }
+        }
+
+        self.avatars.update(|cache| {
+            for (address, url) in avatars {
+                cache.insert((chain_id, address), CacheEntry::new(url, ttl));
+            }
+        });
+    }
+
+    /// Save the cache to localStorage, if persistence is enabled
+    fn save_to_storage(&self) {
+        if !self.config.with_value(|config| config.persist) {
+            return;
+        }
+
+        let persisted = PersistedEnsCache {
+            names: self.names.with_untracked(|names| names.iter().map(|(k, v)| (*k, v.clone())).collect()),
+            addresses: self.addresses.with_untracked(|addresses| addresses.iter().map(|(k, v)| (k.clone(), v.clone())).collect()),
+            avatars: self.avatars.with_untracked(|avatars| avatars.iter().map(|(k, v)| (*k, v.clone())).collect()),
+        };
+
+        let (Some(storage), Ok(json_str)) = (local_storage(), serde_json::to_string(&persisted)) else {
+            return;
+        };
+
+        let _ = storage.set_item(STORAGE_KEY, &json_str);
+    }
+}
+
+/// Whether `key` has an unexpired entry, without tracking the signal
+fn is_fresh<K, V>(cache: RwSignal<HashMap<K, CacheEntry<V>>>, key: &K) -> bool
+where
+    K: Eq + Hash + Send + Sync + 'static,
+    V: Send + Sync + 'static,
+{
+    cache.with_untracked(|cache| cache.get(key).is_some_and(CacheEntry::is_fresh))
+}
+
+/// Drop expired entries loaded from storage
+fn fresh_entries<K: Eq + Hash, V>(entries: Vec<(K, CacheEntry<V>)>) -> HashMap<K, CacheEntry<V>> {
+    entries.into_iter().filter(|(_, entry)| entry.is_fresh()).collect()
+}
+
+/// Current Unix time in milliseconds
+fn now() -> u64 {
+    js_sys::Date::now() as u64
+}
+
+/// Load the persisted cache from localStorage
+fn load_from_storage() -> Option<PersistedEnsCache> {
+    let json_str = local_storage()?.get_item(STORAGE_KEY).ok()??;
+
+    serde_json::from_str(&json_str).ok()
+}
+
+/// Provide the ENS cache to the component tree
+///
+/// Must be called after [`provide_connection_state`](crate::state::connection::provide_connection_state),
+/// since lookups go through the consumer's transports.
+pub fn provide_ens_cache(config: EnsCacheConfig) -> EnsCache {
+    let cache = EnsCache::new(config, use_connection_state());
+    provide_context(cache);
+    cache
+}
+
+/// Access the ENS cache from any component
+///
+/// This will panic if called outside of a NexumKitProvider.
+pub fn use_ens_cache() -> EnsCache {
+    expect_context::<EnsCache>()
+}
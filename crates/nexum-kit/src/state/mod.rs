@@ -1,7 +1,11 @@
 pub mod modal;
 pub mod connection;
 pub mod transaction;
+pub mod ens;
+pub mod toast;
 
 pub use modal::{ModalState, provide_modal_state, use_modal_state};
-pub use connection::{ConnectionState, ConnectionStatus, WalletProvider, provide_connection_state, use_connection_state};
-pub use transaction::{Transaction, TransactionStatus, TransactionStore, provide_transaction_store, use_transaction_store};
+pub use connection::{ConnectionState, ConnectionStatus, PersistedConnection, WalletProvider, provide_connection_state, use_connection_state};
+pub use transaction::{ReplacementReason, Transaction, TransactionStatus, TransactionStore, provide_transaction_store, use_transaction_store};
+pub use ens::{EnsCache, EnsCacheConfig, provide_ens_cache, use_ens_cache};
+pub use toast::{Toast, ToastKind, ToastState, provide_toast_state, use_toast_state};
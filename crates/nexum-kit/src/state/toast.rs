@@ -0,0 +1,154 @@
+use leptos::prelude::*;
+use std::time::Duration;
+
+/// How long a toast stays up unless configured otherwise
+const DEFAULT_DURATION: Duration = Duration::from_secs(5);
+
+/// Most toasts shown at once; older ones are dropped first
+const DEFAULT_MAX_VISIBLE: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastKind {
+    Info,
+    Success,
+    Error,
+    /// Something is in progress, e.g. a pending transaction
+    Pending,
+}
+
+/// A notification shown by the `Toaster`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toast {
+    pub id: u64,
+    pub kind: ToastKind,
+    pub title: String,
+    pub description: Option<String>,
+    /// Link shown below the message, as (label, URL)
+    pub link: Option<(String, String)>,
+    /// `None` keeps the toast until it's dismissed
+    pub duration: Option<Duration>,
+}
+
+impl Toast {
+    /// Create a toast with the default duration
+    ///
+    /// The ID is assigned when the toast is pushed.
+    pub fn new(kind: ToastKind, title: impl Into<String>) -> Self {
+        Self {
+            id: 0,
+            kind,
+            title: title.into(),
+            description: None,
+            link: None,
+            duration: Some(DEFAULT_DURATION),
+        }
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn with_link(mut self, label: impl Into<String>, url: impl Into<String>) -> Self {
+        self.link = Some((label.into(), url.into()));
+        self
+    }
+
+    pub fn with_duration(mut self, duration: Option<Duration>) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+/// Toasts currently shown, newest last
+#[derive(Debug, Clone, Copy)]
+pub struct ToastState {
+    toasts: RwSignal<Vec<Toast>>,
+    next_id: StoredValue<u64>,
+    max_visible: StoredValue<usize>,
+}
+
+impl ToastState {
+    pub fn new() -> Self {
+        Self {
+            toasts: RwSignal::new(Vec::new()),
+            next_id: StoredValue::new(1),
+            max_visible: StoredValue::new(DEFAULT_MAX_VISIBLE),
+        }
+    }
+
+    /// Show a toast, returning its ID
+    pub fn push(&self, mut toast: Toast) -> u64 {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        toast.id = id;
+
+        let duration = toast.duration;
+        let max_visible = self.max_visible.get_value();
+        self.toasts.update(|toasts| {
+            toasts.push(toast);
+            let overflow = toasts.len().saturating_sub(max_visible);
+            toasts.drain(..overflow);
+        });
+
+        if let Some(duration) = duration {
+            let state = *self;
+            set_timeout(move || state.dismiss(id), duration);
+        }
+
+        id
+    }
+
+    /// Shorthand for an info toast
+    pub fn info(&self, title: impl Into<String>) -> u64 {
+        self.push(Toast::new(ToastKind::Info, title))
+    }
+
+    /// Shorthand for a success toast
+    pub fn success(&self, title: impl Into<String>) -> u64 {
+        self.push(Toast::new(ToastKind::Success, title))
+    }
+
+    /// Shorthand for an error toast
+    pub fn error(&self, title: impl Into<String>) -> u64 {
+        self.push(Toast::new(ToastKind::Error, title))
+    }
+
+    /// Remove a toast; unknown IDs are ignored
+    pub fn dismiss(&self, id: u64) {
+        self.toasts.update(|toasts| toasts.retain(|toast| toast.id != id));
+    }
+
+    /// Remove all toasts
+    pub fn clear(&self) {
+        self.toasts.set(Vec::new());
+    }
+
+    /// Change how many toasts are shown at once
+    pub fn set_max_visible(&self, max_visible: usize) {
+        self.max_visible.set_value(max_visible.max(1));
+    }
+
+    /// Toasts currently shown, oldest first
+    pub fn toasts(&self) -> Signal<Vec<Toast>> {
+        self.toasts.into()
+    }
+}
+
+impl Default for ToastState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Provide toast state in the Leptos context
+pub fn provide_toast_state() -> ToastState {
+    let state = ToastState::new();
+    provide_context(state);
+    state
+}
+
+/// Get toast state from Leptos context
+pub fn use_toast_state() -> ToastState {
+    expect_context::<ToastState>()
+}
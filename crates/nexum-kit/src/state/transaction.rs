@@ -1,29 +1,177 @@
 use leptos::prelude::*;
-use alloy::primitives::{Address, TxHash};
+use leptos::leptos_dom::helpers::IntervalHandle;
+use alloy::consensus::Transaction as _;
+use alloy::network::TransactionResponse;
+use alloy::primitives::{Address, Bytes, TxHash, U256};
+use alloy::transports::TransportError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use web_sys::window;
+use std::time::Duration;
+use crate::state::connection::{use_connection_state, ConnectionState, WalletProvider};
+use crate::utils::storage::{local_storage, on_storage_change, StorageListener};
 
 const STORAGE_KEY: &str = "nexumkit_transactions";
 
+/// Version of the persisted transaction payload, bumped on breaking changes
+const STORAGE_VERSION: u32 = 2;
+
+/// Chain of transactions migrated from storage without a chain ID
+///
+/// They are moved to a chain their account connects on once that chain
+/// confirms it knows them.
+const UNKNOWN_CHAIN_ID: u64 = 0;
+
+/// How often pending transactions are checked for a receipt
+const RECEIPT_POLL_INTERVAL: Duration = Duration::from_secs(4);
+
+/// Confirmations after which a transaction is no longer re-checked
+const CONFIRMATION_TARGET: u64 = 12;
+
+/// How many recent blocks are searched for a replacing transaction
+const REPLACEMENT_SCAN_DEPTH: u64 = 20;
+
+/// How long a transaction can stay pending while unknown to the node, with no
+/// nonce to tell whether it was replaced
+const UNKNOWN_TRANSACTION_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TransactionStatus {
     Pending,
     Confirmed,
     Failed,
+    /// Another transaction with the same nonce was mined instead
+    Replaced {
+        /// `None` if the replacement wasn't found, e.g. because it was mined
+        /// before the last scanned blocks
+        by: Option<TxHash>,
+        reason: ReplacementReason,
+    },
+}
+
+impl TransactionStatus {
+    /// Translation key describing the status, e.g. `transaction.confirmed`
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            TransactionStatus::Pending => "transaction.pending",
+            TransactionStatus::Confirmed => "transaction.confirmed",
+            TransactionStatus::Failed => "transaction.failed",
+            TransactionStatus::Replaced { reason: ReplacementReason::SpeedUp, .. } => "transaction.sped_up",
+            TransactionStatus::Replaced { reason: ReplacementReason::Cancel, .. } => "transaction.cancelled",
+            TransactionStatus::Replaced { reason: ReplacementReason::Other, .. } => "transaction.replaced",
+        }
+    }
+}
+
+/// Why a transaction was replaced, judged by comparing it with its replacement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReplacementReason {
+    /// Same call resubmitted with a higher fee
+    SpeedUp,
+    /// Zero-value transfer to the sender itself
+    Cancel,
+    Other,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Transaction {
     pub hash: TxHash,
+    pub chain_id: u64,
+    pub from: Address,
+    /// `None` for contract deployments
+    pub to: Option<Address>,
+    pub nonce: Option<u64>,
     pub status: TransactionStatus,
+    /// Block the transaction was included in, once a receipt was found
+    pub block_number: Option<u64>,
+    pub confirmations: u64,
     pub timestamp: u64,
     pub description: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+impl Transaction {
+    /// Create a pending transaction sent at the current time
+    pub fn new(hash: TxHash, chain_id: u64, from: Address, to: Option<Address>) -> Self {
+        Self {
+            hash,
+            chain_id,
+            from,
+            to,
+            nonce: None,
+            status: TransactionStatus::Pending,
+            block_number: None,
+            confirmations: 0,
+            timestamp: js_sys::Date::now() as u64,
+            description: None,
+        }
+    }
+
+    pub fn with_nonce(mut self, nonce: u64) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the transaction still needs a receipt or more confirmations
+    fn is_unsettled(&self) -> bool {
+        match self.status {
+            TransactionStatus::Pending => true,
+            TransactionStatus::Confirmed | TransactionStatus::Failed => self.confirmations < CONFIRMATION_TARGET,
+            TransactionStatus::Replaced { .. } => false,
+        }
+    }
+
+    /// Whether the transaction was sent long enough ago to count as dropped
+    /// when the node doesn't know it
+    fn is_forgotten(&self, now: u64) -> bool {
+        now.saturating_sub(self.timestamp) > UNKNOWN_TRANSACTION_TIMEOUT.as_millis() as u64
+    }
+
+    /// How far along the transaction is, for picking between two copies of it
+    fn progress(&self) -> (bool, u64, bool) {
+        (self.status != TransactionStatus::Pending, self.confirmations, self.nonce.is_some())
+    }
+}
+
+/// Status of a transaction whose replacement couldn't be found
+const UNKNOWN_REPLACEMENT: TransactionStatus = TransactionStatus::Replaced {
+    by: None,
+    reason: ReplacementReason::Other,
+};
+
+/// Transactions are grouped per account and chain
+type TransactionKey = (Address, u64);
+
+/// Transaction as persisted before chain-aware records (version 1)
+#[derive(Deserialize)]
+struct LegacyTransaction {
+    hash: TxHash,
+    status: TransactionStatus,
+    timestamp: u64,
+    description: Option<String>,
+}
+
+/// Versioned localStorage payload
+#[derive(Serialize, Deserialize)]
+struct PersistedTransactions {
+    version: u32,
+    transactions: Vec<Transaction>,
+}
+
+#[derive(Debug, Clone, Copy)]
 pub struct TransactionStore {
-    transactions: RwSignal<HashMap<Address, Vec<Transaction>>>,
+    transactions: RwSignal<HashMap<TransactionKey, Vec<Transaction>>>,
+    /// Whether a round of receipt requests is still running
+    polling: StoredValue<bool>,
+    /// Value and calldata of pending transactions, to classify replacements
+    originals: StoredValue<HashMap<TxHash, (U256, Bytes)>>,
+    /// Last block searched for replacements, per chain
+    scanned_to: StoredValue<HashMap<u64, u64>>,
+    /// Listener for changes made by other tabs
+    storage_listener: StoredValue<Option<StorageListener>, LocalStorage>,
 }
 
 impl TransactionStore {
@@ -33,51 +181,334 @@ impl TransactionStore {
 
         Self {
             transactions: RwSignal::new(initial),
+            polling: StoredValue::new(false),
+            originals: StoredValue::new(HashMap::new()),
+            scanned_to: StoredValue::new(HashMap::new()),
+            storage_listener: StoredValue::new_local(None),
         }
     }
 
-    /// Add a new transaction to the store
-    pub fn add_transaction(&self, address: Address, tx: Transaction) {
-        self.transactions.update(|txs| {
-            txs.entry(address)
-                .or_insert_with(Vec::new)
-                .push(tx);
+    /// Poll receipts of pending transactions while a wallet is connected
+    ///
+    /// Pending transactions loaded from localStorage are picked up as soon as
+    /// the session is restored, so nothing is left pending after a reload.
+    pub fn watch_receipts(&self, connection: ConnectionState) {
+        let store = *self;
+        let interval = StoredValue::new_local(None::<IntervalHandle>);
+
+        // Polling rounds write to the store, so only a change in whether anything
+        // is left to poll may restart the interval
+        let has_unsettled = Memo::new(move |_| {
+            let chain_id = connection.chain_id.get();
+            store.transactions.with(|txs| {
+                txs.values().flatten().any(|tx| Some(tx.chain_id) == chain_id && tx.is_unsettled())
+            })
         });
 
-        self.save_to_storage();
+        Effect::new(move || {
+            let (Some(provider), Some(address), Some(chain_id)) =
+                (connection.provider.get(), connection.address.get(), connection.chain_id.get())
+            else {
+                return;
+            };
+            store.adopt_unknown_chain(provider, address, chain_id);
+        });
+
+        Effect::new(move || {
+            let provider = connection.provider.get();
+            let chain_id = connection.chain_id.get();
+
+            let has_unsettled = has_unsettled.get();
+
+            // Restart on every provider change so polling uses the current chain
+            if let Some(handle) = interval.try_update_value(Option::take).flatten() {
+                handle.clear();
+            }
+
+            let (Some(provider), Some(chain_id), true) = (provider, chain_id, has_unsettled) else {
+                return;
+            };
+
+            store.poll_receipts(provider.clone(), chain_id);
+
+            match set_interval_with_handle(move || store.poll_receipts(provider.clone(), chain_id), RECEIPT_POLL_INTERVAL) {
+                Ok(handle) => interval.set_value(Some(handle)),
+                Err(e) => log::error!("Failed to start receipt polling: {:?}", e),
+            }
+        });
+    }
+
+    /// Fetch receipts of pending transactions on `chain_id` and update their
+    /// status and confirmations
+    fn poll_receipts(&self, provider: WalletProvider, chain_id: u64) {
+        if self.polling.get_value() {
+            return;
+        }
+
+        let unsettled: Vec<Transaction> = self.transactions.with_untracked(|txs| {
+            txs.values()
+                .flatten()
+                .filter(|tx| tx.chain_id == chain_id && tx.is_unsettled())
+                .cloned()
+                .collect()
+        });
+
+        if unsettled.is_empty() {
+            return;
+        }
+
+        let store = *self;
+        self.polling.set_value(true);
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Err(e) = store.check_transactions(&provider, chain_id, unsettled).await {
+                log::warn!("Failed to check transactions: {}", e);
+            }
+            store.polling.set_value(false);
+        });
+    }
+
+    /// One polling round: receipts, confirmations and replacements
+    async fn check_transactions(
+        &self,
+        provider: &WalletProvider,
+        chain_id: u64,
+        unsettled: Vec<Transaction>,
+    ) -> Result<(), TransportError> {
+        // The block number must be read before account nonces; see `find_replacements`
+        let latest_block = provider.get_block_number().await?;
+        let mut account_nonces: HashMap<Address, u64> = HashMap::new();
+        let mut replaced: Vec<Transaction> = Vec::new();
+
+        for mut tx in unsettled {
+            let block_number = match tx.block_number {
+                Some(block_number) => block_number,
+                None => match provider.get_transaction_receipt(tx.hash).await? {
+                    Some(receipt) => {
+                        let status = if receipt.status() {
+                            TransactionStatus::Confirmed
+                        } else {
+                            TransactionStatus::Failed
+                        };
+                        log::debug!("Transaction {} {:?}", tx.hash, status);
+                        self.update_transaction_status(tx.from, chain_id, tx.hash, status);
+
+                        // Receipts of pending blocks have no block number yet
+                        let Some(block_number) = receipt.block_number else {
+                            continue;
+                        };
+                        block_number
+                    }
+                    None => {
+                        if tx.status != TransactionStatus::Pending {
+                            continue;
+                        }
+
+                        // Not mined yet. If the account's nonce moved past it,
+                        // another transaction took its place.
+                        let Some(nonce) = self.tracked_nonce(provider, &mut tx).await? else {
+                            // Without a nonce, a transaction the node forgot can't be
+                            // matched with its replacement
+                            if tx.is_forgotten(js_sys::Date::now() as u64) {
+                                log::info!("Transaction {} is unknown to the node, marking it replaced", tx.hash);
+                                self.update_transaction_status(tx.from, chain_id, tx.hash, UNKNOWN_REPLACEMENT);
+                            }
+                            continue;
+                        };
+                        let account_nonce = match account_nonces.get(&tx.from) {
+                            Some(account_nonce) => *account_nonce,
+                            None => {
+                                let account_nonce = provider.get_transaction_count(tx.from).number(latest_block).await?;
+                                account_nonces.insert(tx.from, account_nonce);
+                                account_nonce
+                            }
+                        };
+                        if account_nonce > nonce {
+                            replaced.push(tx);
+                        }
+                        continue;
+                    }
+                },
+            };
+
+            let confirmations = latest_block.saturating_sub(block_number) + 1;
+            if tx.block_number == Some(block_number) && tx.confirmations == confirmations {
+                continue;
+            }
+            self.update_transaction(tx.from, chain_id, tx.hash, |tx| {
+                tx.block_number = Some(block_number);
+                tx.confirmations = confirmations;
+            });
+        }
+
+        if !replaced.is_empty() {
+            self.find_replacements(provider, chain_id, latest_block, replaced).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Nonce of a tracked transaction, fetched from the node if it wasn't recorded
+    ///
+    /// Also remembers the original value and calldata while the transaction is
+    /// still known, to tell a speed-up from other replacements later.
+    async fn tracked_nonce(&self, provider: &WalletProvider, tx: &mut Transaction) -> Result<Option<u64>, TransportError> {
+        let known = self.originals.with_value(|originals| originals.contains_key(&tx.hash));
+        if known && tx.nonce.is_some() {
+            return Ok(tx.nonce);
+        }
+
+        let Some(original) = provider.get_transaction_by_hash(tx.hash).await? else {
+            return Ok(tx.nonce);
+        };
+
+        self.originals.update_value(|originals| {
+            originals.insert(tx.hash, (original.value(), original.input().clone()));
+        });
+
+        if tx.nonce.is_none() {
+            let nonce = original.nonce();
+            tx.nonce = Some(nonce);
+            self.update_transaction(tx.from, tx.chain_id, tx.hash, |tx| tx.nonce = Some(nonce));
+        }
+
+        Ok(tx.nonce)
+    }
+
+    /// Scan blocks for transactions with the same sender and nonce as `replaced`
+    ///
+    /// Each block is scanned once, up to `REPLACEMENT_SCAN_DEPTH` blocks back.
+    /// Since the account nonces are read at `latest_block`, every replacement
+    /// seen through a nonce is in a block up to it. Transactions whose
+    /// replacement is older than the scanned blocks are settled without it.
+    async fn find_replacements(
+        &self,
+        provider: &WalletProvider,
+        chain_id: u64,
+        latest_block: u64,
+        mut replaced: Vec<Transaction>,
+    ) -> Result<(), TransportError> {
+        let scanned_to = self.scanned_to.with_value(|scanned| scanned.get(&chain_id).copied());
+        let from_block = scanned_to
+            .map_or(0, |block| block + 1)
+            .max(latest_block.saturating_sub(REPLACEMENT_SCAN_DEPTH - 1));
+
+        for number in (from_block..=latest_block).rev() {
+            if replaced.is_empty() {
+                break;
+            }
+
+            let Some(block) = provider.get_block_by_number(number.into()).full().await? else {
+                continue;
+            };
+
+            for candidate in block.transactions.txns() {
+                let Some(index) = replaced.iter().position(|tx| {
+                    candidate.from() == tx.from
+                        && Some(candidate.nonce()) == tx.nonce
+                        && candidate.tx_hash() != tx.hash
+                }) else {
+                    continue;
+                };
+
+                let tx = replaced.swap_remove(index);
+                let original = self.originals.with_value(|originals| originals.get(&tx.hash).cloned());
+                let reason = replacement_reason(&tx, original.as_ref(), candidate.to(), candidate.value(), candidate.input());
+                let by = candidate.tx_hash();
+                log::info!("Transaction {} was replaced by {} ({:?})", tx.hash, by, reason);
+
+                self.update_transaction_status(tx.from, chain_id, tx.hash, TransactionStatus::Replaced { by: Some(by), reason });
+
+                // Track the replacement in its place, so its receipt gets picked up
+                self.add_transaction(Transaction {
+                    hash: by,
+                    to: candidate.to(),
+                    nonce: tx.nonce,
+                    status: TransactionStatus::Pending,
+                    block_number: None,
+                    confirmations: 0,
+                    ..tx
+                });
+            }
+        }
+
+        self.scanned_to.update_value(|scanned| {
+            scanned.insert(chain_id, latest_block);
+        });
+
+        for tx in replaced {
+            // The transaction itself may have been mined since its receipt was checked
+            if provider.get_transaction_receipt(tx.hash).await?.is_some() {
+                continue;
+            }
+
+            log::info!("Transaction {} was replaced by a transaction outside the scanned blocks", tx.hash);
+            self.update_transaction_status(tx.from, chain_id, tx.hash, UNKNOWN_REPLACEMENT);
+        }
+
+        Ok(())
+    }
+
+    /// Add a new transaction to the store, under its sender and chain
+    ///
+    /// Transactions that are already tracked are ignored.
+    pub fn add_transaction(&self, tx: Transaction) {
+        self.mutate(|txs| {
+            let list = txs.entry((tx.from, tx.chain_id)).or_insert_with(Vec::new);
+            if !list.iter().any(|t| t.hash == tx.hash) {
+                list.push(tx);
+            }
+        });
     }
 
     /// Update the status of a transaction
     pub fn update_transaction_status(
         &self,
         address: Address,
+        chain_id: u64,
         hash: TxHash,
         status: TransactionStatus,
     ) {
-        self.transactions.update(|txs| {
-            if let Some(address_txs) = txs.get_mut(&address) {
-                if let Some(tx) = address_txs.iter_mut().find(|t| t.hash == hash) {
-                    tx.status = status;
-                }
+        self.update_transaction(address, chain_id, hash, |tx| tx.status = status);
+    }
+
+    /// Modify a stored transaction in place
+    fn update_transaction(
+        &self,
+        address: Address,
+        chain_id: u64,
+        hash: TxHash,
+        f: impl FnOnce(&mut Transaction),
+    ) {
+        self.mutate(|txs| {
+            if let Some(tx) = txs.get_mut(&(address, chain_id)).and_then(|list| list.iter_mut().find(|t| t.hash == hash)) {
+                f(tx);
             }
         });
-
-        self.save_to_storage();
     }
 
-    /// Get all transactions for an address
-    pub fn get_transactions(&self, address: Address) -> Vec<Transaction> {
+    /// Get all transactions for an address on a chain
+    pub fn get_transactions(&self, address: Address, chain_id: u64) -> Vec<Transaction> {
         self.transactions.with(|txs| {
-            txs.get(&address)
-                .map(|list| list.clone())
+            txs.get(&(address, chain_id))
+                .cloned()
                 .unwrap_or_default()
         })
     }
 
-    /// Get pending transactions for an address
-    pub fn get_pending(&self, address: Address) -> Vec<Transaction> {
+    /// Get every stored transaction, across accounts and chains
+    pub fn all_transactions(&self) -> Vec<Transaction> {
+        self.transactions.with(|txs| txs.values().flatten().cloned().collect())
+    }
+
+    /// Find a stored transaction by hash
+    pub fn find_transaction(&self, hash: TxHash) -> Option<Transaction> {
+        self.transactions.with(|txs| txs.values().flatten().find(|tx| tx.hash == hash).cloned())
+    }
+
+    /// Get pending transactions for an address on a chain
+    pub fn get_pending(&self, address: Address, chain_id: u64) -> Vec<Transaction> {
         self.transactions.with(|txs| {
-            txs.get(&address)
+            txs.get(&(address, chain_id))
                 .map(|list| {
                     list.iter()
                         .filter(|tx| matches!(tx.status, TransactionStatus::Pending))
@@ -88,46 +519,256 @@ impl TransactionStore {
         })
     }
 
-    /// Get the number of pending transactions for an address
-    pub fn pending_count(&self, address: Address) -> usize {
-        self.get_pending(address).len()
+    /// Get the number of pending transactions for an address on a chain
+    pub fn pending_count(&self, address: Address, chain_id: u64) -> usize {
+        self.get_pending(address, chain_id).len()
+    }
+
+    /// Clear all transactions for an address on a chain
+    pub fn clear_transactions(&self, address: Address, chain_id: u64) {
+        self.mutate(|txs| {
+            txs.remove(&(address, chain_id));
+        });
+    }
+
+    /// Move migrated transactions of `address` without a chain to `chain_id`,
+    /// if they exist on it
+    ///
+    /// Transactions the chain doesn't know keep no chain, so they get no
+    /// explorer link and aren't polled on a chain they aren't on.
+    fn adopt_unknown_chain(&self, provider: WalletProvider, address: Address, chain_id: u64) {
+        let key = (address, UNKNOWN_CHAIN_ID);
+        let hashes: Vec<TxHash> = self.transactions.with_untracked(|txs| {
+            txs.get(&key)
+                .map(|list| list.iter().map(|tx| tx.hash).collect())
+                .unwrap_or_default()
+        });
+        if hashes.is_empty() {
+            return;
+        }
+
+        let store = *self;
+        wasm_bindgen_futures::spawn_local(async move {
+            let mut found = Vec::new();
+            for hash in hashes {
+                match provider.get_transaction_by_hash(hash).await {
+                    Ok(Some(_)) => found.push(hash),
+                    Ok(None) => {}
+                    Err(e) => {
+                        log::warn!("Failed to look up migrated transaction {}: {}", hash, e);
+                        return;
+                    }
+                }
+            }
+            if found.is_empty() {
+                return;
+            }
+
+            store.mutate(|txs| {
+                let Some(unknown) = txs.remove(&key) else {
+                    return;
+                };
+                let (mut adopted, rest): (Vec<_>, Vec<_>) = unknown.into_iter().partition(|tx| found.contains(&tx.hash));
+                if !rest.is_empty() {
+                    txs.insert(key, rest);
+                }
+                for tx in &mut adopted {
+                    tx.chain_id = chain_id;
+                }
+
+                let list = txs.entry((address, chain_id)).or_default();
+                list.extend(adopted);
+                list.sort_by_key(|tx| tx.timestamp);
+            });
+        });
     }
 
-    /// Clear all transactions for an address
-    pub fn clear_transactions(&self, address: Address) {
+    /// Apply a change on top of the latest persisted transactions and save them
+    ///
+    /// Reading first means a change never overwrites what another tab saved
+    /// since this tab last synced.
+    fn mutate(&self, f: impl FnOnce(&mut HashMap<TransactionKey, Vec<Transaction>>)) {
+        let persisted = read_persisted();
+
         self.transactions.update(|txs| {
-            txs.remove(&address);
+            if let Some(persisted) = persisted {
+                *txs = merge_persisted(txs, persisted);
+            }
+            f(txs);
         });
 
         self.save_to_storage();
     }
 
+    /// Merge changes other tabs make to the persisted transactions
+    pub fn sync_across_tabs(&self) {
+        let store = *self;
+        let listener = on_storage_change(STORAGE_KEY, move |value| {
+            let persisted = match value.as_deref().map(parse_persisted) {
+                Some(Ok(persisted)) => persisted,
+                Some(Err(e)) => {
+                    log::warn!("Ignoring unreadable transactions from another tab: {}", e);
+                    return;
+                }
+                None => Vec::new(),
+            };
+
+            store.transactions.update(|txs| *txs = merge_persisted(txs, persisted));
+        });
+
+        self.storage_listener.set_value(listener);
+    }
+
     /// Load transactions from localStorage
-    fn load_from_storage() -> Option<HashMap<Address, Vec<Transaction>>> {
-        let window = window()?;
-        let storage = window.local_storage().ok()??;
+    ///
+    /// Payloads that can't be read are kept under a backup key instead of being
+    /// overwritten by the next save.
+    fn load_from_storage() -> Option<HashMap<TransactionKey, Vec<Transaction>>> {
+        let storage = local_storage()?;
         let json_str = storage.get_item(STORAGE_KEY).ok()??;
 
-        serde_json::from_str(&json_str).ok()
+        match parse_persisted(&json_str) {
+            Ok(transactions) => Some(group_transactions(transactions)),
+            Err(e) => {
+                log::warn!("Discarding unreadable stored transactions: {}", e);
+                let _ = storage.set_item(&format!("{}_backup", STORAGE_KEY), &json_str);
+                None
+            }
+        }
     }
 
     /// Save transactions to localStorage
     fn save_to_storage(&self) {
-        if let Some(window) = window() {
-            if let Ok(Some(storage)) = window.local_storage() {
-                let txs = self.transactions.get();
-                if let Ok(json_str) = serde_json::to_string(&txs) {
-                    let _ = storage.set_item(STORAGE_KEY, &json_str);
-                }
+        let persisted = PersistedTransactions {
+            version: STORAGE_VERSION,
+            transactions: self.transactions.with_untracked(|txs| txs.values().flatten().cloned().collect()),
+        };
+
+        let (Some(storage), Ok(json_str)) = (local_storage(), serde_json::to_string(&persisted)) else {
+            return;
+        };
+
+        let _ = storage.set_item(STORAGE_KEY, &json_str);
+    }
+}
+
+/// Read the persisted transactions without touching an unreadable payload
+fn read_persisted() -> Option<Vec<Transaction>> {
+    let json_str = local_storage()?.get_item(STORAGE_KEY).ok()??;
+
+    parse_persisted(&json_str).ok()
+}
+
+/// Combine persisted transactions with the ones in memory
+///
+/// The persisted list decides which transactions exist, so another tab's
+/// "Clear all" carries over. For transactions known to both, whichever copy
+/// is further along wins, so a stale write never undoes a confirmation.
+fn merge_persisted(
+    local: &HashMap<TransactionKey, Vec<Transaction>>,
+    persisted: Vec<Transaction>,
+) -> HashMap<TransactionKey, Vec<Transaction>> {
+    let mut merged = group_transactions(persisted);
+
+    for (key, list) in merged.iter_mut() {
+        let Some(local_list) = local.get(key) else {
+            continue;
+        };
+        for tx in list.iter_mut() {
+            let newer = local_list.iter().find(|t| t.hash == tx.hash && t.progress() > tx.progress());
+            if let Some(local_tx) = newer {
+                *tx = local_tx.clone();
             }
         }
     }
+
+    merged
+}
+
+/// Classify a replacement the way wallets create them
+///
+/// Without the original's value and calldata (e.g. after a reload), a
+/// replacement to the same recipient is assumed to be a speed-up.
+fn replacement_reason(
+    tx: &Transaction,
+    original: Option<&(U256, Bytes)>,
+    to: Option<Address>,
+    value: U256,
+    input: &Bytes,
+) -> ReplacementReason {
+    let same_call = original.is_none_or(|(original_value, original_input)| {
+        *original_value == value && original_input == input
+    });
+
+    if to == tx.to && same_call {
+        ReplacementReason::SpeedUp
+    } else if to == Some(tx.from) && value.is_zero() {
+        ReplacementReason::Cancel
+    } else {
+        ReplacementReason::Other
+    }
+}
+
+/// Parse a persisted payload of any known version
+///
+/// Version 1 was an unversioned map of address to transactions without a chain.
+fn parse_persisted(json_str: &str) -> Result<Vec<Transaction>, String> {
+    let value: serde_json::Value = serde_json::from_str(json_str).map_err(|e| e.to_string())?;
+
+    match value.get("version").and_then(|v| v.as_u64()) {
+        Some(version) if version == u64::from(STORAGE_VERSION) => {
+            serde_json::from_value::<PersistedTransactions>(value)
+                .map(|persisted| persisted.transactions)
+                .map_err(|e| e.to_string())
+        }
+        Some(version) => Err(format!("unsupported version {}", version)),
+        None => serde_json::from_value::<HashMap<Address, Vec<LegacyTransaction>>>(value)
+            .map(migrate_v1)
+            .map_err(|e| e.to_string()),
+    }
+}
+
+/// Migrate version 1 transactions, whose chain is unknown
+fn migrate_v1(legacy: HashMap<Address, Vec<LegacyTransaction>>) -> Vec<Transaction> {
+    legacy.into_iter()
+        .flat_map(|(from, list)| {
+            list.into_iter().map(move |tx| Transaction {
+                hash: tx.hash,
+                chain_id: UNKNOWN_CHAIN_ID,
+                from,
+                to: None,
+                nonce: None,
+                status: tx.status,
+                block_number: None,
+                confirmations: 0,
+                timestamp: tx.timestamp,
+                description: tx.description,
+            })
+        })
+        .collect()
+}
+
+/// Group transactions by sender and chain, oldest first
+fn group_transactions(transactions: Vec<Transaction>) -> HashMap<TransactionKey, Vec<Transaction>> {
+    let mut grouped: HashMap<TransactionKey, Vec<Transaction>> = HashMap::new();
+    for tx in transactions {
+        grouped.entry((tx.from, tx.chain_id)).or_default().push(tx);
+    }
+    for list in grouped.values_mut() {
+        list.sort_by_key(|tx| tx.timestamp);
+    }
+    grouped
 }
 
 /// Provide transaction store in the Leptos context
+///
+/// Must be called after `provide_connection_state`, since receipts are
+/// fetched through the connected provider.
 pub fn provide_transaction_store() -> TransactionStore {
     let store = TransactionStore::new();
-    provide_context(store.clone());
+    store.watch_receipts(use_connection_state());
+    store.sync_across_tabs();
+    provide_context(store);
     store
 }
 
@@ -150,4 +791,113 @@ mod tests {
         assert_ne!(pending, confirmed);
         assert_ne!(confirmed, failed);
     }
+
+    fn sample_transaction() -> Transaction {
+        Transaction {
+            hash: TxHash::with_last_byte(2),
+            chain_id: 1,
+            from: Address::with_last_byte(1),
+            to: Some(Address::with_last_byte(3)),
+            nonce: Some(7),
+            status: TransactionStatus::Pending,
+            block_number: None,
+            confirmations: 0,
+            timestamp: 1,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_replacement_reason() {
+        let tx = sample_transaction();
+        let original = (U256::from(5), Bytes::from_static(&[1, 2]));
+
+        assert_eq!(
+            replacement_reason(&tx, Some(&original), tx.to, U256::from(5), &Bytes::from_static(&[1, 2])),
+            ReplacementReason::SpeedUp
+        );
+        assert_eq!(
+            replacement_reason(&tx, Some(&original), Some(tx.from), U256::ZERO, &Bytes::new()),
+            ReplacementReason::Cancel
+        );
+        assert_eq!(
+            replacement_reason(&tx, Some(&original), tx.to, U256::from(6), &Bytes::from_static(&[1, 2])),
+            ReplacementReason::Other
+        );
+        assert_eq!(
+            replacement_reason(&tx, None, tx.to, U256::from(6), &Bytes::new()),
+            ReplacementReason::SpeedUp
+        );
+    }
+
+    #[test]
+    fn test_parse_persisted_v1_migrates_without_chain() {
+        let json = r#"{"0x0000000000000000000000000000000000000001":[{"hash":"0x0000000000000000000000000000000000000000000000000000000000000002","status":"Confirmed","timestamp":1,"description":"Mint"}]}"#;
+
+        let txs = parse_persisted(json).unwrap();
+        assert_eq!(txs.len(), 1);
+        assert_eq!(txs[0].chain_id, UNKNOWN_CHAIN_ID);
+        assert_eq!(txs[0].from, Address::with_last_byte(1));
+        assert_eq!(txs[0].status, TransactionStatus::Confirmed);
+        assert_eq!(txs[0].description.as_deref(), Some("Mint"));
+    }
+
+    #[test]
+    fn test_parse_persisted_round_trip() {
+        let tx = Transaction {
+            chain_id: 10,
+            status: TransactionStatus::Replaced { by: Some(TxHash::with_last_byte(4)), reason: ReplacementReason::Cancel },
+            ..sample_transaction()
+        };
+        let json = serde_json::to_string(&PersistedTransactions { version: STORAGE_VERSION, transactions: vec![tx] }).unwrap();
+
+        let txs = parse_persisted(&json).unwrap();
+        assert_eq!(txs[0].chain_id, 10);
+        assert_eq!(txs[0].nonce, Some(7));
+        assert_eq!(txs[0].status, TransactionStatus::Replaced { by: Some(TxHash::with_last_byte(4)), reason: ReplacementReason::Cancel });
+    }
+
+    #[test]
+    fn test_merge_persisted() {
+        let tx = sample_transaction();
+        let confirmed = Transaction { status: TransactionStatus::Confirmed, confirmations: 3, ..tx.clone() };
+        let removed = Transaction { hash: TxHash::with_last_byte(9), ..tx.clone() };
+        let local = group_transactions(vec![confirmed.clone(), removed]);
+
+        // A stale pending copy doesn't undo the local confirmation, and
+        // transactions missing from storage were cleared elsewhere
+        let merged = merge_persisted(&local, vec![tx.clone()]);
+        let list = &merged[&(tx.from, tx.chain_id)];
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].status, TransactionStatus::Confirmed);
+
+        // Transactions only in storage were added elsewhere
+        let added = Transaction { hash: TxHash::with_last_byte(8), timestamp: 2, ..tx.clone() };
+        let merged = merge_persisted(&local, vec![confirmed, added]);
+        assert_eq!(merged[&(tx.from, tx.chain_id)].len(), 2);
+    }
+
+    #[test]
+    fn test_unknown_replacement_settles() {
+        let sent_at = 1_000_000;
+        let tx = Transaction { nonce: None, timestamp: sent_at, ..sample_transaction() };
+        let timeout = UNKNOWN_TRANSACTION_TIMEOUT.as_millis() as u64;
+
+        // A transaction the node doesn't know yet may still be propagating
+        assert!(!tx.is_forgotten(sent_at + timeout));
+        assert!(tx.is_forgotten(sent_at + timeout + 1));
+
+        // Once settled without its replacement, it is no longer polled
+        let replaced = Transaction { status: UNKNOWN_REPLACEMENT, ..tx };
+        assert!(!replaced.is_unsettled());
+        assert_eq!(replaced.status.i18n_key(), "transaction.replaced");
+
+        let json = serde_json::to_string(&PersistedTransactions { version: STORAGE_VERSION, transactions: vec![replaced] }).unwrap();
+        assert_eq!(parse_persisted(&json).unwrap()[0].status, UNKNOWN_REPLACEMENT);
+    }
+
+    #[test]
+    fn test_parse_persisted_unknown_version() {
+        assert!(parse_persisted(r#"{"version":99,"transactions":[]}"#).is_err());
+    }
 }
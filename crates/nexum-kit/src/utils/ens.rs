@@ -0,0 +1,429 @@
+//! ENS resolution against a mainnet provider
+//!
+//! Names are normalized with ENSIP-15 before hashing, reverse records are only trusted
+//! when the name resolves back to the same address, and avatars follow ENSIP-12
+//! (HTTP(S), IPFS, Arweave and data URIs, plus `eip155:` NFT avatars).
+//!
+//! The `*_batch` functions resolve many names or addresses at once through Multicall3,
+//! so a list of lookups costs a handful of `eth_call`s instead of several per entry.
+
+use alloy::ens::{namehash, reverse_address, EnsError, EnsRegistry, EnsResolver, ProviderEnsExt, ENS_ADDRESS};
+use alloy::primitives::{address, Address, Bytes, B256, U256};
+use alloy::providers::Provider;
+use alloy::sol;
+use alloy::sol_types::SolCall;
+use base64::Engine;
+use thiserror::Error;
+
+const IPFS_GATEWAY: &str = "https://ipfs.io";
+const ARWEAVE_GATEWAY: &str = "https://arweave.net";
+
+/// Multicall3, deployed at the same address on mainnet and most other chains
+const MULTICALL3_ADDRESS: Address = address!("0xcA11bde05977b3631167028862bE2a173976CA11");
+
+sol! {
+    #[sol(rpc)]
+    interface IMulticall3 {
+        struct Call3 {
+            address target;
+            bool allowFailure;
+            bytes callData;
+        }
+
+        struct Result {
+            bool success;
+            bytes returnData;
+        }
+
+        function aggregate3(Call3[] calldata calls) external payable returns (Result[] memory returnData);
+    }
+
+    #[sol(rpc)]
+    interface IERC721Metadata {
+        function ownerOf(uint256 tokenId) external view returns (address);
+        function tokenURI(uint256 tokenId) external view returns (string);
+    }
+
+    #[sol(rpc)]
+    interface IERC1155MetadataURI {
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+        function uri(uint256 id) external view returns (string);
+    }
+}
+
+/// Error returned by ENS lookups
+#[derive(Debug, Error)]
+pub enum EnsLookupError {
+    /// The ENS registry or resolver call failed
+    #[error(transparent)]
+    Ens(#[from] EnsError),
+
+    /// A call to an avatar NFT contract failed
+    #[error(transparent)]
+    Contract(#[from] alloy::contract::Error),
+
+    /// The avatar NFT metadata couldn't be fetched
+    #[error("Failed to fetch NFT metadata: {0}")]
+    Metadata(String),
+}
+
+/// NFT referenced by an `eip155:` avatar record (ENSIP-12)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NftAvatar {
+    pub chain_id: u64,
+    pub standard: NftStandard,
+    pub contract: Address,
+    pub token_id: U256,
+}
+
+/// Token standard of an NFT avatar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NftStandard {
+    Erc721,
+    Erc1155,
+}
+
+/// Normalize an ENS name according to ENSIP-15
+///
+/// Returns `None` if the name contains disallowed characters.
+pub fn normalize_name(name: &str) -> Option<String> {
+    ens_normalize_rs::normalize(name).ok()
+}
+
+/// Resolve an address to its primary ENS name
+///
+/// The reverse record is only returned if it is normalized and its forward
+/// record points back to `address`, since anyone can set any reverse name.
+pub async fn lookup_name<P: Provider>(provider: &P, address: Address) -> Result<Option<String>, EnsLookupError> {
+    let name = match provider.lookup_address(&address).await {
+        Ok(name) => name,
+        Err(EnsError::ResolverNotFound(_)) => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+
+    if name.is_empty() || normalize_name(&name).as_deref() != Some(name.as_str()) {
+        return Ok(None);
+    }
+
+    let verified = resolve_address(provider, &name).await? == Some(address);
+    Ok(verified.then_some(name))
+}
+
+/// Resolve an ENS name to the address it points to
+pub async fn resolve_address<P: Provider>(provider: &P, name: &str) -> Result<Option<Address>, EnsLookupError> {
+    let Some(name) = normalize_name(name) else {
+        return Ok(None);
+    };
+
+    match provider.resolve_name(&name).await {
+        Ok(address) if address != Address::ZERO => Ok(Some(address)),
+        Ok(_) | Err(EnsError::ResolverNotFound(_)) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Resolve the `avatar` text record of a name to an image URL
+///
+/// `owner` is the address the name resolves to; NFT avatars are only shown if
+/// that address owns the token.
+pub async fn lookup_avatar<P: Provider>(provider: &P, name: &str, owner: Address) -> Result<Option<String>, EnsLookupError> {
+    match provider.lookup_txt(name, "avatar").await {
+        Ok(record) => resolve_avatar_record(provider, &record, owner).await,
+        Err(EnsError::ResolverNotFound(_)) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Resolve the primary names of many addresses in a few Multicall3 batches
+///
+/// Same rules as [`lookup_name`]: unnormalized names and names whose forward
+/// record doesn't point back to the address are `None`.
+pub async fn lookup_name_batch<P: Provider>(provider: &P, addresses: &[Address]) -> Result<Vec<Option<String>>, EnsLookupError> {
+    let nodes: Vec<B256> = addresses.iter().map(|addr| namehash(&reverse_address(addr))).collect();
+    let resolvers = resolver_batch(provider, &nodes).await?;
+
+    let calls = resolvers.iter().zip(&nodes)
+        .filter_map(|(resolver, node)| Some((resolver.as_ref().copied()?, EnsResolver::nameCall { node: *node })))
+        .collect();
+    let mut reverse_names = aggregate(provider, calls).await?.into_iter();
+
+    let names: Vec<Option<String>> = resolvers.iter()
+        .map(|resolver| {
+            let name = if resolver.is_some() { reverse_names.next().flatten() } else { None };
+            name.filter(|name| !name.is_empty() && normalize_name(name).as_deref() == Some(name.as_str()))
+        })
+        .collect();
+
+    // Forward-verify every reverse record in one more round of batches
+    let claimed: Vec<String> = names.iter().flatten().cloned().collect();
+    let mut forward = resolve_address_batch(provider, &claimed).await?.into_iter();
+
+    Ok(names.into_iter()
+        .zip(addresses)
+        .map(|(name, addr)| {
+            let name = name?;
+            (forward.next().flatten() == Some(*addr)).then_some(name)
+        })
+        .collect())
+}
+
+/// Resolve many ENS names to addresses in a few Multicall3 batches
+pub async fn resolve_address_batch<P: Provider>(provider: &P, names: &[String]) -> Result<Vec<Option<Address>>, EnsLookupError> {
+    let nodes: Vec<Option<B256>> = names.iter()
+        .map(|name| normalize_name(name).map(|name| namehash(&name)))
+        .collect();
+    let valid: Vec<B256> = nodes.iter().flatten().copied().collect();
+    let mut resolvers = resolver_batch(provider, &valid).await?.into_iter();
+
+    let targets: Vec<Option<(Address, B256)>> = nodes.iter()
+        .map(|node| Some((resolvers.next().flatten()?, (*node)?)))
+        .collect();
+    let calls = targets.iter().flatten()
+        .map(|(resolver, node)| (*resolver, EnsResolver::addrCall { node: *node }))
+        .collect();
+    let mut addresses = aggregate(provider, calls).await?.into_iter();
+
+    Ok(targets.iter()
+        .map(|target| {
+            target.as_ref()?;
+            addresses.next().flatten().filter(|addr| *addr != Address::ZERO)
+        })
+        .collect())
+}
+
+/// Fetch the raw `avatar` text records of many names in a few Multicall3 batches
+///
+/// Pass each record to [`resolve_avatar_record`] to get an image URL.
+pub async fn avatar_record_batch<P: Provider>(provider: &P, names: &[String]) -> Result<Vec<Option<String>>, EnsLookupError> {
+    let nodes: Vec<B256> = names.iter().map(|name| namehash(name)).collect();
+    let resolvers = resolver_batch(provider, &nodes).await?;
+
+    let calls = resolvers.iter().zip(&nodes)
+        .filter_map(|(resolver, node)| {
+            let call = EnsResolver::textCall { node: *node, key: "avatar".to_string() };
+            Some((resolver.as_ref().copied()?, call))
+        })
+        .collect();
+    let mut records = aggregate(provider, calls).await?.into_iter();
+
+    Ok(resolvers.iter()
+        .map(|resolver| {
+            resolver.as_ref()?;
+            records.next().flatten().filter(|record| !record.trim().is_empty())
+        })
+        .collect())
+}
+
+/// Resolve a raw `avatar` text record to an image URL
+pub async fn resolve_avatar_record<P: Provider>(provider: &P, record: &str, owner: Address) -> Result<Option<String>, EnsLookupError> {
+    let record = record.trim();
+
+    if record.is_empty() {
+        return Ok(None);
+    }
+
+    match parse_nft_avatar(record) {
+        Some(nft) => nft_image(provider, &nft, owner).await,
+        None => Ok(resolve_uri(record)),
+    }
+}
+
+/// Parse an ENSIP-12 NFT avatar, e.g. `eip155:1/erc721:0xb47e3cd837dDF8e4c57F05d70Ab865de6e193BBB/1`
+pub fn parse_nft_avatar(record: &str) -> Option<NftAvatar> {
+    let rest = record.strip_prefix("eip155:")?;
+    let mut parts = rest.split('/');
+
+    let chain_id = parts.next()?.parse().ok()?;
+    let (standard, contract) = parts.next()?.split_once(':')?;
+    let token_id = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let standard = match standard.to_ascii_lowercase().as_str() {
+        "erc721" => NftStandard::Erc721,
+        "erc1155" => NftStandard::Erc1155,
+        _ => return None,
+    };
+
+    Some(NftAvatar {
+        chain_id,
+        standard,
+        contract: contract.parse().ok()?,
+        token_id,
+    })
+}
+
+/// Turn an avatar or metadata URI into a URL a browser can load
+///
+/// IPFS and Arweave URIs are rewritten to public gateways; HTTP(S) and data URIs
+/// are returned as-is.
+pub fn resolve_uri(uri: &str) -> Option<String> {
+    let uri = uri.trim();
+
+    if uri.starts_with("https://") || uri.starts_with("http://") || uri.starts_with("data:") {
+        Some(uri.to_string())
+    } else if let Some(path) = uri.strip_prefix("ipfs://") {
+        let path = path.strip_prefix("ipfs/").unwrap_or(path);
+        Some(format!("{}/ipfs/{}", IPFS_GATEWAY, path))
+    } else if let Some(path) = uri.strip_prefix("ipns://") {
+        Some(format!("{}/ipns/{}", IPFS_GATEWAY, path))
+    } else {
+        uri.strip_prefix("ar://").map(|path| format!("{}/{}", ARWEAVE_GATEWAY, path))
+    }
+}
+
+/// Look up the resolver of each node in the ENS registry
+async fn resolver_batch<P: Provider>(provider: &P, nodes: &[B256]) -> Result<Vec<Option<Address>>, EnsLookupError> {
+    let calls = nodes.iter()
+        .map(|node| (ENS_ADDRESS, EnsRegistry::resolverCall { node: *node }))
+        .collect();
+
+    Ok(aggregate(provider, calls).await?
+        .into_iter()
+        .map(|resolver| resolver.filter(|addr| *addr != Address::ZERO))
+        .collect())
+}
+
+/// Run calls of the same kind through Multicall3's `aggregate3`
+///
+/// Each call may fail on its own (e.g. a resolver without `name`); failed or
+/// undecodable calls come back as `None`.
+async fn aggregate<P: Provider, C: SolCall>(provider: &P, calls: Vec<(Address, C)>) -> Result<Vec<Option<C::Return>>, EnsLookupError> {
+    if calls.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let calls = calls.into_iter()
+        .map(|(target, call)| IMulticall3::Call3 {
+            target,
+            allowFailure: true,
+            callData: Bytes::from(call.abi_encode()),
+        })
+        .collect();
+
+    let results = IMulticall3::new(MULTICALL3_ADDRESS, provider)
+        .aggregate3(calls)
+        .call()
+        .await?;
+
+    Ok(results.into_iter()
+        .map(|result| {
+            result.success
+                .then(|| C::abi_decode_returns(&result.returnData).ok())
+                .flatten()
+        })
+        .collect())
+}
+
+/// Image of an NFT avatar owned by `owner`
+async fn nft_image<P: Provider>(provider: &P, nft: &NftAvatar, owner: Address) -> Result<Option<String>, EnsLookupError> {
+    // The NFT can only be read if it lives on the provider's chain
+    let chain_id = provider.get_chain_id().await.map_err(alloy::contract::Error::from)?;
+    if nft.chain_id != chain_id {
+        log::debug!("Skipping NFT avatar on chain {}", nft.chain_id);
+        return Ok(None);
+    }
+
+    let metadata_uri = match nft.standard {
+        NftStandard::Erc721 => {
+            let contract = IERC721Metadata::new(nft.contract, provider);
+            if contract.ownerOf(nft.token_id).call().await? != owner {
+                return Ok(None);
+            }
+            contract.tokenURI(nft.token_id).call().await?
+        }
+        NftStandard::Erc1155 => {
+            let contract = IERC1155MetadataURI::new(nft.contract, provider);
+            if contract.balanceOf(owner, nft.token_id).call().await?.is_zero() {
+                return Ok(None);
+            }
+            // ERC-1155 substitutes `{id}` with the zero-padded hex token ID
+            contract.uri(nft.token_id).call().await?
+                .replace("{id}", &format!("{:064x}", nft.token_id))
+        }
+    };
+
+    let metadata = fetch_metadata(&metadata_uri).await?;
+    let image = ["image", "image_url"]
+        .iter()
+        .find_map(|key| metadata.get(*key).and_then(|v| v.as_str()));
+
+    Ok(image.and_then(resolve_uri))
+}
+
+/// Fetch NFT metadata JSON from a token URI
+async fn fetch_metadata(uri: &str) -> Result<serde_json::Value, EnsLookupError> {
+    if let Some(json) = decode_json_data_uri(uri) {
+        return serde_json::from_str(&json).map_err(|e| EnsLookupError::Metadata(e.to_string()));
+    }
+
+    let url = resolve_uri(uri)
+        .ok_or_else(|| EnsLookupError::Metadata(format!("Unsupported token URI: {}", uri)))?;
+
+    reqwest::get(&url)
+        .await
+        .map_err(|e| EnsLookupError::Metadata(e.to_string()))?
+        .json()
+        .await
+        .map_err(|e| EnsLookupError::Metadata(e.to_string()))
+}
+
+/// Decode an inline `data:application/json` URI
+fn decode_json_data_uri(uri: &str) -> Option<String> {
+    let (params, data) = uri.strip_prefix("data:application/json")?.split_once(',')?;
+
+    if params.ends_with(";base64") {
+        let bytes = base64::engine::general_purpose::STANDARD.decode(data).ok()?;
+        String::from_utf8(bytes).ok()
+    } else {
+        urlencoding::decode(data).ok().map(|json| json.into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("Nick.ETH").as_deref(), Some("nick.eth"));
+        assert_eq!(normalize_name("vitalik.eth").as_deref(), Some("vitalik.eth"));
+        assert_eq!(normalize_name("bad\u{0000}.eth"), None);
+    }
+
+    #[test]
+    fn test_parse_nft_avatar() {
+        let nft = parse_nft_avatar("eip155:1/erc721:0xb47e3cd837dDF8e4c57F05d70Ab865de6e193BBB/1").unwrap();
+        assert_eq!(nft.chain_id, 1);
+        assert_eq!(nft.standard, NftStandard::Erc721);
+        assert_eq!(nft.token_id, U256::from(1));
+
+        let nft = parse_nft_avatar("eip155:1/erc1155:0x495f947276749ce646f68ac8c248420045cb7b5e/42").unwrap();
+        assert_eq!(nft.standard, NftStandard::Erc1155);
+
+        assert_eq!(parse_nft_avatar("https://example.com/avatar.png"), None);
+        assert_eq!(parse_nft_avatar("eip155:1/erc20:0xb47e3cd837dDF8e4c57F05d70Ab865de6e193BBB/1"), None);
+    }
+
+    #[test]
+    fn test_resolve_uri() {
+        assert_eq!(resolve_uri("https://example.com/a.png").as_deref(), Some("https://example.com/a.png"));
+        assert_eq!(resolve_uri("ipfs://QmHash/a.png").as_deref(), Some("https://ipfs.io/ipfs/QmHash/a.png"));
+        assert_eq!(resolve_uri("ipfs://ipfs/QmHash").as_deref(), Some("https://ipfs.io/ipfs/QmHash"));
+        assert_eq!(resolve_uri("ar://tx").as_deref(), Some("https://arweave.net/tx"));
+        assert_eq!(resolve_uri("ftp://example.com"), None);
+    }
+
+    #[test]
+    fn test_decode_json_data_uri() {
+        assert_eq!(
+            decode_json_data_uri("data:application/json;base64,eyJpbWFnZSI6ImEifQ==").as_deref(),
+            Some(r#"{"image":"a"}"#)
+        );
+        assert_eq!(
+            decode_json_data_uri("data:application/json;utf8,%7B%22image%22%3A%22a%22%7D").as_deref(),
+            Some(r#"{"image":"a"}"#)
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use alloy::primitives::Address;
+use alloy::primitives::{Address, TxHash, U256};
+use alloy_eip1193::{Chain, NamedChain};
 
 /// Format an Ethereum address for display
 ///
@@ -29,27 +30,75 @@ pub fn format_address(address: &Address) -> String {
 /// # Example
 ///
 /// ```rust
+/// use alloy::primitives::U256;
 /// use nexumkit::utils::format_balance;
 ///
 /// // 1.5 ETH (1500000000000000000 wei)
-/// let formatted = format_balance(1_500_000_000_000_000_000, 18);
+/// let formatted = format_balance(U256::from(1_500_000_000_000_000_000u128), 18);
 /// assert_eq!(formatted, "1.5000");
 /// ```
-pub fn format_balance(balance: u128, decimals: u8) -> String {
-    if balance == 0 {
+pub fn format_balance(balance: U256, decimals: u8) -> String {
+    if balance.is_zero() {
         return "0.0000".to_string();
     }
 
-    let divisor = 10u128.pow(decimals as u32);
-    let whole = balance / divisor;
-    let fractional = balance % divisor;
+    // Token-supplied decimals can exceed what 10^decimals fits in; the balance is then all fraction
+    let (whole, fractional) = match U256::from(10).checked_pow(U256::from(decimals)) {
+        Some(divisor) => (balance / divisor, balance % divisor),
+        None => (U256::ZERO, balance),
+    };
 
-    // Calculate fractional part with 4 decimal places
-    let fractional_display = fractional / (divisor / 10_000);
+    // Calculate fractional part with 4 decimal places, scaling down rather than
+    // multiplying up so large decimals cannot overflow
+    let fractional_display = match decimals.checked_sub(4) {
+        Some(shift) => U256::from(10)
+            .checked_pow(U256::from(shift))
+            .map_or(U256::ZERO, |scale| fractional / scale),
+        None => fractional * U256::from(10).pow(U256::from(4 - decimals)),
+    };
 
     format!("{}.{:04}", whole, fractional_display)
 }
 
+/// Format a chain ID as a human-readable network name
+///
+/// Well-known networks get their marketing name (e.g. "Ethereum" for mainnet), other
+/// chains known to alloy-chains are title-cased from their slug, and unknown chains
+/// fall back to "Chain <id>".
+pub fn format_chain_name(chain_id: u64) -> String {
+    let chain = Chain::from_id(chain_id);
+    let Some(named) = chain.named() else {
+        return format!("Chain {}", chain_id);
+    };
+
+    match named {
+        NamedChain::Mainnet => "Ethereum".to_string(),
+        NamedChain::Arbitrum => "Arbitrum One".to_string(),
+        NamedChain::Optimism => "OP Mainnet".to_string(),
+        NamedChain::BinanceSmartChain => "BNB Smart Chain".to_string(),
+        _ => named
+            .as_str()
+            .split('-')
+            .map(|word| {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" "),
+    }
+}
+
+/// Block explorer link for a transaction
+///
+/// Returns `None` for chains without a known Etherscan-style explorer.
+pub fn explorer_tx_url(chain_id: u64, hash: &TxHash) -> Option<String> {
+    let (_, explorer_url) = Chain::from_id(chain_id).etherscan_urls()?;
+    Some(format!("{}/tx/{}", explorer_url.trim_end_matches('/'), hash))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -64,15 +113,45 @@ mod tests {
     #[test]
     fn test_format_balance() {
         // 1.5 ETH
-        assert_eq!(format_balance(1_500_000_000_000_000_000, 18), "1.5000");
+        assert_eq!(format_balance(U256::from(1_500_000_000_000_000_000u128), 18), "1.5000");
 
         // 0.1234 ETH
-        assert_eq!(format_balance(123_400_000_000_000_000, 18), "0.1234");
+        assert_eq!(format_balance(U256::from(123_400_000_000_000_000u128), 18), "0.1234");
 
         // 1000 USDC (6 decimals)
-        assert_eq!(format_balance(1_000_000_000, 6), "1000.0000");
+        assert_eq!(format_balance(U256::from(1_000_000_000u64), 6), "1000.0000");
 
         // Zero balance
-        assert_eq!(format_balance(0, 18), "0.0000");
+        assert_eq!(format_balance(U256::ZERO, 18), "0.0000");
+
+        // More than u128::MAX wei
+        assert_eq!(format_balance(U256::from(u128::MAX) * U256::from(10), 18), "3402823669209384634633.7460");
+
+        // Fewer than 4 decimals
+        assert_eq!(format_balance(U256::from(15u64), 1), "1.5000");
+
+        // Decimals past 10^77 must not overflow
+        assert_eq!(format_balance(U256::MAX, 77), "1.1579");
+        assert_eq!(format_balance(U256::MAX, 78), "0.1157");
+        assert_eq!(format_balance(U256::MAX, 255), "0.0000");
+    }
+
+    #[test]
+    fn test_format_chain_name() {
+        assert_eq!(format_chain_name(1), "Ethereum");
+        assert_eq!(format_chain_name(137), "Polygon");
+        assert_eq!(format_chain_name(100), "Gnosis");
+        assert_eq!(format_chain_name(11155111), "Sepolia");
+        assert_eq!(format_chain_name(999_999_999_999), "Chain 999999999999");
+    }
+
+    #[test]
+    fn test_explorer_tx_url() {
+        let hash = TxHash::with_last_byte(1);
+        assert_eq!(
+            explorer_tx_url(1, &hash).as_deref(),
+            Some("https://etherscan.io/tx/0x0000000000000000000000000000000000000000000000000000000000000001")
+        );
+        assert_eq!(explorer_tx_url(999_999_999_999, &hash), None);
     }
 }
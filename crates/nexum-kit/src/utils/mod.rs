@@ -1,3 +1,5 @@
 pub mod format;
+pub mod ens;
+pub mod storage;
 
-pub use format::{format_address, format_balance};
+pub use format::{format_address, format_balance, format_chain_name};
@@ -0,0 +1,51 @@
+//! localStorage access, and changes made to it by other tabs of the same origin
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{Storage, StorageEvent};
+
+/// Get the browser's localStorage, if available
+pub fn local_storage() -> Option<Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Listener for `storage` events, removed when dropped
+#[must_use = "the listener is removed when the handle is dropped"]
+pub struct StorageListener {
+    closure: Closure<dyn FnMut(StorageEvent)>,
+}
+
+impl Drop for StorageListener {
+    fn drop(&mut self) {
+        if let Some(window) = web_sys::window() {
+            let _ = window.remove_event_listener_with_callback("storage", self.closure.as_ref().unchecked_ref());
+        }
+    }
+}
+
+/// Call `on_change` with the new value whenever another tab writes `key`
+///
+/// The value is `None` if the key was removed or the storage was cleared.
+/// Browsers don't fire `storage` events in the tab that made the change.
+pub fn on_storage_change<F>(key: &'static str, on_change: F) -> Option<StorageListener>
+where
+    F: Fn(Option<String>) + 'static,
+{
+    let window = web_sys::window()?;
+
+    let closure = Closure::wrap(Box::new(move |event: StorageEvent| {
+        // A `null` key means `localStorage.clear()`
+        match event.key() {
+            Some(changed) if changed == key => on_change(event.new_value()),
+            None => on_change(None),
+            Some(_) => {}
+        }
+    }) as Box<dyn FnMut(StorageEvent)>);
+
+    if let Err(e) = window.add_event_listener_with_callback("storage", closure.as_ref().unchecked_ref()) {
+        log::error!("Failed to listen for storage events: {:?}", e);
+        return None;
+    }
+
+    Some(StorageListener { closure })
+}
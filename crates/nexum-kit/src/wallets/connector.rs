@@ -1,6 +1,12 @@
+use alloy::network::Ethereum;
+use alloy::providers::RootProvider;
+use alloy_eip1193::ext::PermissionRequest;
+use alloy_eip1193::{Eip1193Error, Eip1193Transport};
 use wasm_bindgen::prelude::*;
 use web_sys::js_sys::Reflect;
 use web_sys::Window;
+use crate::error::ConnectError;
+use crate::provider::Eip1193;
 
 /// Provider flags used to identify specific wallets
 ///
@@ -137,3 +143,20 @@ pub fn has_provider_flag(provider: &JsValue, flag: ProviderFlag) -> bool {
 pub fn is_metamask(provider: &JsValue) -> bool {
     has_provider_flag(provider, ProviderFlag::IsMetaMask)
 }
+
+/// Revoke the site's `eth_accounts` permission, so the wallet stops exposing accounts
+/// until the user connects again
+///
+/// Returns `false` if the wallet doesn't support `wallet_revokePermissions`; the user
+/// then has to disconnect the site from the wallet itself.
+pub async fn revoke_account_access(transport: Eip1193Transport) -> Result<bool, ConnectError> {
+    let provider = RootProvider::<Ethereum>::new(transport.into_client());
+
+    match provider.revoke_permissions(PermissionRequest::eth_accounts()).await {
+        Ok(()) => Ok(true),
+        Err(e) => match Eip1193Error::from_transport_error(&e) {
+            Some(error) if error.is_unsupported_method() => Ok(false),
+            _ => Err(e.into()),
+        },
+    }
+}
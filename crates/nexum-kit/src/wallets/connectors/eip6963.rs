@@ -0,0 +1,71 @@
+use crate::wallets::wallet::{WalletConnector, WalletMetadata};
+use crate::wallets::eip6963::EIP6963Provider;
+use crate::provider::Eip1193;
+use crate::error::ConnectError;
+use alloy::primitives::Address;
+use alloy::providers::RootProvider;
+use alloy::network::Ethereum;
+use alloy_eip1193::Eip1193Transport;
+use wasm_bindgen::prelude::*;
+
+/// Generic injected wallet connector
+///
+/// Connects to whichever wallet announced the provider via EIP-6963, so each
+/// wallet talks to its own provider object instead of `window.ethereum`.
+pub struct Eip6963Connector {
+    metadata: WalletMetadata,
+    provider: JsValue,
+}
+
+impl Eip6963Connector {
+    pub fn new(provider: EIP6963Provider) -> Self {
+        Self {
+            metadata: WalletMetadata::from(&provider.info),
+            provider: provider.provider,
+        }
+    }
+}
+
+impl From<EIP6963Provider> for Eip6963Connector {
+    fn from(provider: EIP6963Provider) -> Self {
+        Self::new(provider)
+    }
+}
+
+impl WalletConnector for Eip6963Connector {
+    fn metadata(&self) -> &WalletMetadata {
+        &self.metadata
+    }
+
+    async fn connect(&self) -> Result<Address, ConnectError> {
+        let transport = Eip1193Transport::new(self.provider.clone());
+        let client = transport.into_client();
+        let provider = RootProvider::<Ethereum>::new(client);
+
+        let accounts = provider.request_accounts().await?;
+
+        let address = accounts
+            .first()
+            .copied()
+            .ok_or_else(|| ConnectError::NoAccounts(self.metadata.name.clone()))?;
+
+        log::info!("{} connected: {:?}", self.metadata.name, address);
+
+        Ok(address)
+    }
+
+    async fn disconnect(&self) -> Result<(), ConnectError> {
+        // Injected wallets don't expose a programmatic disconnect
+        log::info!("{} disconnect requested (manual disconnect required)", self.metadata.name);
+        Ok(())
+    }
+
+    fn is_installed(&self) -> bool {
+        // The wallet announced itself, so it's present in this page
+        true
+    }
+
+    fn get_provider(&self) -> Option<JsValue> {
+        Some(self.provider.clone())
+    }
+}
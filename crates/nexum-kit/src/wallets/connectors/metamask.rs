@@ -1,6 +1,7 @@
 use crate::wallets::wallet::{WalletConnector, WalletMetadata, DownloadUrls, ConnectionMethod};
 use crate::wallets::connector::{get_injected_provider, is_metamask, ProviderFlag};
 use crate::provider::Eip1193;
+use crate::error::ConnectError;
 use alloy::primitives::Address;
 use alloy::providers::RootProvider;
 use alloy::network::Ethereum;
@@ -52,30 +53,29 @@ impl WalletConnector for MetaMaskConnector {
         &self.metadata
     }
 
-    async fn connect(&self) -> Result<Address, JsValue> {
+    async fn connect(&self) -> Result<Address, ConnectError> {
         let ethereum = self
             .get_ethereum()
-            .ok_or_else(|| JsValue::from_str("MetaMask not installed"))?;
+            .ok_or_else(|| ConnectError::NotInstalled(self.metadata.name.clone()))?;
 
         // Use modern RpcClient + Provider pattern with Eip1193 trait
         let transport = Eip1193Transport::new(ethereum);
         let client = transport.into_client();
         let provider = RootProvider::<Ethereum>::new(client);
 
-        let accounts = provider.request_accounts().await
-            .map_err(|e| JsValue::from_str(&format!("Failed to request accounts: {:?}", e)))?;
+        let accounts = provider.request_accounts().await?;
 
         let address = accounts
             .first()
             .copied()
-            .ok_or_else(|| JsValue::from_str("No accounts returned from MetaMask"))?;
+            .ok_or_else(|| ConnectError::NoAccounts(self.metadata.name.clone()))?;
 
         log::info!("MetaMask connected: {:?}", address);
 
         Ok(address)
     }
 
-    async fn disconnect(&self) -> Result<(), JsValue> {
+    async fn disconnect(&self) -> Result<(), ConnectError> {
         // MetaMask doesn't have a programmatic disconnect method
         // The user must disconnect through the MetaMask UI
         log::info!("MetaMask disconnect requested (manual disconnect required)");
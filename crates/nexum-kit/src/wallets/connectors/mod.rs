@@ -1,3 +1,5 @@
 pub mod metamask;
+pub mod eip6963;
 
 pub use metamask::MetaMaskConnector;
+pub use eip6963::Eip6963Connector;
@@ -3,6 +3,7 @@ use wasm_bindgen::JsCast;
 use web_sys::{CustomEvent, Event};
 use serde::{Deserialize, Serialize};
 use js_sys::Reflect;
+use crate::wallets::wallet::WalletMetadata;
 
 /// EIP-6963 Provider Information
 ///
@@ -27,11 +28,51 @@ pub struct EIP6963Provider {
     pub provider: JsValue,
 }
 
+impl From<&EIP6963ProviderInfo> for WalletMetadata {
+    fn from(info: &EIP6963ProviderInfo) -> Self {
+        // The rdns is stable across page loads, unlike the uuid
+        let rdns = (!info.rdns.is_empty()).then(|| info.rdns.clone());
+
+        Self {
+            id: rdns.clone().unwrap_or_else(|| info.uuid.clone()),
+            name: info.name.clone(),
+            rdns,
+            icon_url: info.icon.clone(),
+            icon_background: "#fff".to_string(),
+            icon_accent: None,
+            download_urls: None,
+        }
+    }
+}
+
+/// Handle to an active EIP-6963 discovery
+///
+/// Wallets may announce themselves at any time, so the `eip6963:announceProvider`
+/// listener stays registered until this handle is dropped.
+#[must_use = "discovery stops as soon as the handle is dropped"]
+pub struct Eip6963Discovery {
+    closure: Closure<dyn FnMut(Event)>,
+}
+
+impl Drop for Eip6963Discovery {
+    fn drop(&mut self) {
+        if let Some(window) = web_sys::window() {
+            let _ = window.remove_event_listener_with_callback(
+                "eip6963:announceProvider",
+                self.closure.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
+
 /// Setup EIP-6963 discovery with a callback for reactive updates
 ///
 /// This is the Leptos-friendly version that calls a callback whenever a new provider
 /// is discovered. Use this with a WriteSignal to reactively update your UI.
-pub fn setup_eip6963_discovery<F>(on_provider_discovered: F)
+///
+/// Returns `None` if the listener couldn't be registered. The callback is only
+/// invoked while the returned handle is alive; it must not drop the handle itself.
+pub fn setup_eip6963_discovery<F>(on_provider_discovered: F) -> Option<Eip6963Discovery>
 where
     F: Fn(EIP6963Provider) + 'static,
 {
@@ -39,7 +80,7 @@ where
         Some(w) => w,
         None => {
             log::warn!("No window object available for EIP-6963 discovery");
-            return;
+            return None;
         }
     };
 
@@ -99,7 +140,7 @@ where
                 log::warn!("EIP-6963: No info object in detail");
             }
         }
-    }) as Box<dyn FnMut(_)>);
+    }) as Box<dyn FnMut(Event)>);
 
     // Add event listener
     if let Err(e) = window.add_event_listener_with_callback(
@@ -107,11 +148,10 @@ where
         closure.as_ref().unchecked_ref(),
     ) {
         log::error!("Failed to add EIP-6963 event listener: {:?}", e);
-        return;
+        return None;
     }
 
-    // Keep closure alive
-    closure.forget();
+    let discovery = Eip6963Discovery { closure };
 
     log::info!("EIP-6963: Event listener registered");
 
@@ -124,4 +164,6 @@ where
     } else {
         log::info!("EIP-6963: Dispatched requestProvider event");
     }
+
+    Some(discovery)
 }
@@ -4,5 +4,5 @@ pub mod eip6963;
 pub mod connectors;
 
 pub use wallet::{WalletMetadata, DownloadUrls, WalletConnector, ConnectionMethod};
-pub use connector::get_injected_provider;
-pub use eip6963::{setup_eip6963_discovery, EIP6963Provider, EIP6963ProviderInfo};
+pub use connector::{get_injected_provider, revoke_account_access};
+pub use eip6963::{setup_eip6963_discovery, Eip6963Discovery, EIP6963Provider, EIP6963ProviderInfo};
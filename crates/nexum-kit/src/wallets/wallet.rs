@@ -1,4 +1,9 @@
 use alloy::primitives::Address;
+use alloy::providers::RootProvider;
+use alloy::network::Ethereum;
+use alloy_eip1193::Eip1193Transport;
+use crate::provider::Eip1193;
+use crate::error::ConnectError;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::JsValue;
 
@@ -73,10 +78,29 @@ pub trait WalletConnector {
     /// Connect to the wallet
     ///
     /// Returns the connected address on success
-    fn connect(&self) -> impl std::future::Future<Output = Result<Address, JsValue>> + '_;
+    fn connect(&self) -> impl std::future::Future<Output = Result<Address, ConnectError>> + '_;
+
+    /// Reconnect to the wallet without prompting the user
+    ///
+    /// Returns the first account the wallet has already authorized (via `eth_accounts`),
+    /// or `None` if the dApp is no longer authorized.
+    fn reconnect(&self) -> impl std::future::Future<Output = Result<Option<Address>, ConnectError>> + '_ {
+        async move {
+            let ethereum = self
+                .get_provider()
+                .ok_or_else(|| ConnectError::NotInstalled(self.metadata().name.clone()))?;
+
+            let client = Eip1193Transport::new(ethereum).into_client();
+            let provider = RootProvider::<Ethereum>::new(client);
+
+            let accounts = provider.accounts().await?;
+
+            Ok(accounts.first().copied())
+        }
+    }
 
     /// Disconnect from the wallet
-    fn disconnect(&self) -> impl std::future::Future<Output = Result<(), JsValue>> + '_;
+    fn disconnect(&self) -> impl std::future::Future<Output = Result<(), ConnectError>> + '_;
 
     /// Check if the wallet is installed/available
     fn is_installed(&self) -> bool;
@@ -2,13 +2,11 @@ use leptos::prelude::*;
 use leptos::callback::{Callback, UnsyncCallback};
 use leptos::task::spawn_local;
 use nexum_kit::prelude::*;
-use nexum_kit::components::modals::{ConnectModal, AccountModal};
+use nexum_kit::components::modals::{ConnectModal, AccountModal, ChainModal};
 use nexum_kit::theme::{LightTheme, DarkTheme, MidnightTheme, ThemeOptions, BorderRadius, FontStack, OverlayBlur};
 use std::collections::HashMap;
-use alloy::signers::Signer;
 use alloy::dyn_abi::eip712::TypedData;
 use alloy::primitives::Address;
-use alloy_eip1193::prelude::*;
 
 fn main() {
     console_log::init_with_level(log::Level::Debug).unwrap();
@@ -206,6 +204,8 @@ fn AppContent(
 
                 <ConnectModal />
                 <AccountModal />
+                <ChainModal />
+                <Toaster />
 
                 <p
                     class="text-sm pt-2"
@@ -243,120 +243,76 @@ fn DemoSection(
     });
 
     // State for results
-    let (signature_result, set_signature_result) = signal(None::<String>);
-    let (typed_signature_result, set_typed_signature_result) = signal(None::<String>);
     let (balance_result, set_balance_result) = signal(None::<String>);
     let (vitalik_balance_result, set_vitalik_balance_result) = signal(None::<String>);
     let (block_number_result, set_block_number_result) = signal(None::<String>);
     let (send_tx_result, set_send_tx_result) = signal(None::<String>);
 
+    // Signing goes through whichever wallet is connected
+    let sign_message = use_sign_message();
+    let sign_typed_data = use_sign_typed_data();
+
     // Handler: Personal Sign
     let handle_personal_sign = move |_| {
-        // Read values before entering async context
-        let addr = wallet.address_untracked();
-
-        if addr.is_none() {
-            set_signature_result.set(Some("No address connected".to_string()));
-            return;
-        }
-
-        set_signature_result.set(Some("Signing...".to_string()));
-        spawn_local(async move {
-            let addr = addr.unwrap();
-            match Eip1193Transport::get_ethereum() {
-                Ok(ethereum) => {
-                    let signer = Eip1193Signer::new(ethereum, addr);
-                    let message = b"Hello from Nexum-Kit! This is a test message.";
-
-                    match signer.sign_message(message).await {
-                        Ok(signature) => {
-                            let sig_str = format!("0x{}", hex::encode(signature.as_bytes()));
-                            log::info!("Personal Sign signature: {}", sig_str);
-                            set_signature_result.set(Some(sig_str));
-                        }
-                        Err(e) => {
-                            let err_msg = format!("Sign failed: {:?}", e);
-                            log::error!("{}", err_msg);
-                            set_signature_result.set(Some(err_msg));
-                        }
-                    }
-                }
-                Err(e) => {
-                    let err_msg = format!("Failed to get ethereum: {:?}", e);
-                    log::error!("{}", err_msg);
-                    set_signature_result.set(Some(err_msg));
-                }
-            }
-        });
+        sign_message.sign("Hello from Nexum-Kit! This is a test message.");
     };
 
     // Handler: EIP-712 Typed Data Sign
     let handle_typed_sign = move |_| {
-        // Read values before entering async context
-        let addr = wallet.address_untracked();
-        let chain_id = wallet.chain_id_untracked().unwrap_or(1);
-
-        if addr.is_none() {
-            set_typed_signature_result.set(Some("No address connected".to_string()));
+        let Some(addr) = wallet.address_untracked() else {
             return;
-        }
+        };
+        let chain_id = wallet.chain_id_untracked().unwrap_or(1);
 
-        set_typed_signature_result.set(Some("Signing...".to_string()));
-        spawn_local(async move {
-            let addr = addr.unwrap();
-            match Eip1193Transport::get_ethereum() {
-                Ok(ethereum) => {
-                    let signer = Eip1193Signer::new(ethereum, addr);
-
-                    // Create EIP-712 typed data
-                    let typed_data_json = serde_json::json!({
-                        "types": {
-                            "EIP712Domain": [
-                                {"name": "name", "type": "string"},
-                                {"name": "version", "type": "string"},
-                                {"name": "chainId", "type": "uint256"}
-                            ],
-                            "Person": [
-                                {"name": "name", "type": "string"},
-                                {"name": "wallet", "type": "address"}
-                            ]
-                        },
-                        "domain": {
-                            "name": "Nexum-Kit",
-                            "version": "1",
-                            "chainId": chain_id
-                        },
-                        "primaryType": "Person",
-                        "message": {
-                            "name": "Alice",
-                            "wallet": format!("{:?}", addr)
-                        }
-                    });
+        // Create EIP-712 typed data
+        let typed_data_json = serde_json::json!({
+            "types": {
+                "EIP712Domain": [
+                    {"name": "name", "type": "string"},
+                    {"name": "version", "type": "string"},
+                    {"name": "chainId", "type": "uint256"}
+                ],
+                "Person": [
+                    {"name": "name", "type": "string"},
+                    {"name": "wallet", "type": "address"}
+                ]
+            },
+            "domain": {
+                "name": "Nexum-Kit",
+                "version": "1",
+                "chainId": chain_id
+            },
+            "primaryType": "Person",
+            "message": {
+                "name": "Alice",
+                "wallet": format!("{:?}", addr)
+            }
+        });
 
-                    log::info!("EIP-712 typed data: {}", serde_json::to_string_pretty(&typed_data_json).unwrap());
+        log::info!("EIP-712 typed data: {}", serde_json::to_string_pretty(&typed_data_json).unwrap());
 
-                    let typed_data: TypedData = serde_json::from_value(typed_data_json).unwrap();
+        let typed_data: TypedData = serde_json::from_value(typed_data_json).unwrap();
+        sign_typed_data.sign(typed_data);
+    };
 
-                    match signer.sign_dynamic_typed_data(&typed_data).await {
-                        Ok(signature) => {
-                            let sig_str = format!("0x{}", hex::encode(signature.as_bytes()));
-                            log::info!("EIP-712 signature: {}", sig_str);
-                            set_typed_signature_result.set(Some(sig_str));
-                        }
-                        Err(e) => {
-                            let err_msg = format!("Sign failed: {:?}", e);
-                            log::error!("{}", err_msg);
-                            set_typed_signature_result.set(Some(err_msg));
-                        }
-                    }
-                }
-                Err(e) => {
-                    let err_msg = format!("Failed to get ethereum: {:?}", e);
-                    log::error!("{}", err_msg);
-                    set_typed_signature_result.set(Some(err_msg));
-                }
-            }
-        });
+    // Show the outcome of the sign actions
+    let signature_result = move || {
+        if sign_message.pending.get() {
+            Some("Signing...".to_string())
+        } else if let Some(signature) = sign_message.signature.get() {
+            Some(format!("0x{}", hex::encode(signature.as_bytes())))
+        } else {
+            sign_message.error.get().map(|e| format!("Sign failed: {}", e))
+        }
+    };
+    let typed_signature_result = move || {
+        if sign_typed_data.pending.get() {
+            Some("Signing...".to_string())
+        } else if let Some(signature) = sign_typed_data.signature.get() {
+            Some(format!("0x{}", hex::encode(signature.as_bytes())))
+        } else {
+            sign_typed_data.error.get().map(|e| format!("Sign failed: {}", e))
+        }
     };
 
     // Handler: Fetch My Balance
@@ -453,6 +409,7 @@ fn DemoSection(
     };
 
     // Handler: Send Transaction
+    let send_tx = use_send_transaction();
     let handle_send_transaction = move |_| {
         // Read values before entering async context
         let provider = wallet.provider_untracked();
@@ -467,7 +424,6 @@ fn DemoSection(
         spawn_local(async move {
             use alloy::rpc::types::TransactionRequest;
 
-            let provider = provider.unwrap();
             let from_addr = addr.unwrap();
 
             // First, switch to Gnosis Chain (chain ID 100) using direct RPC call
@@ -524,23 +480,21 @@ fn DemoSection(
             log::info!("Sending transaction on Gnosis Chain: {:?}", tx);
             set_send_tx_result.set(Some("Waiting for approval...".to_string()));
 
-            match provider.send_transaction(tx).await {
-                Ok(pending_tx) => {
-                    let tx_hash = *pending_tx.tx_hash();
-                    let result = format!("✅ Tx sent on Gnosis!\nHash: {:?}\nView: https://gnosisscan.io/tx/{:?}", tx_hash, tx_hash);
-                    log::info!("Transaction sent: {:?}", tx_hash);
-                    set_send_tx_result.set(Some(result));
-                }
-                Err(e) => {
-                    // Use the helper to extract EIP-1193 error and format user message
-                    let user_msg = format_transport_error(&e);
-                    log::error!("Transaction error: {}", e);
-                    set_send_tx_result.set(Some(user_msg));
-                }
-            }
+            // Sent transactions are recorded in the transaction store automatically
+            send_tx.send(tx, Some("Send 0.00001 xDAI to self".to_string()));
         });
     };
 
+    // Show the outcome of the send action
+    Effect::new(move || {
+        if let Some(tx_hash) = send_tx.hash.get() {
+            let result = format!("✅ Tx sent on Gnosis!\nHash: {:?}\nView: https://gnosisscan.io/tx/{:?}", tx_hash, tx_hash);
+            set_send_tx_result.set(Some(result));
+        } else if let Some(e) = send_tx.error.get() {
+            set_send_tx_result.set(Some(format!("❌ {}", e)));
+        }
+    });
+
     view! {
         <Show when=move || wallet.is_connected.get()>
             <div
@@ -569,7 +523,7 @@ fn DemoSection(
                             "Sign Message (personal_sign)"
                         </button>
 
-                        {move || signature_result.get().map(|sig| view! {
+                        {move || signature_result().map(|sig| view! {
                             <div
                                 class="p-3 rounded-lg text-xs break-all"
                                 style="background: rgba(0,0,0,0.1);"
@@ -591,7 +545,7 @@ fn DemoSection(
                             "Sign Typed Data (EIP-712)"
                         </button>
 
-                        {move || typed_signature_result.get().map(|sig| view! {
+                        {move || typed_signature_result().map(|sig| view! {
                             <div
                                 class="p-3 rounded-lg text-xs break-all"
                                 style="background: rgba(0,0,0,0.1);"